@@ -33,10 +33,11 @@ impl Agent for CmykColorAgent {
     match msg {
       Request::CmykColorChangeMsg(cmyk) => {
         if color_validate::is_valid_cmyk(&cmyk) {
-          let color = Color::from_cmyk(cmyk);
-          self
-            .current_color_dispatcher
-            .send(CurrentColorRequest::UpdateColor(color));
+          if let Ok(color) = Color::from_cmyk(cmyk) {
+            self
+              .current_color_dispatcher
+              .send(CurrentColorRequest::UpdateColor(color));
+          }
         }
       }
     }
@@ -1,104 +1,223 @@
+use gloo::events::EventListener;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent};
+use yew::utils::window;
 use yew::worker::{Agent, AgentLink, Context, HandlerId};
 
 use crate::libs::color_transform::Color;
-
-use crate::libs::color_transform::hsv_color::HSV;
-use crate::libs::color_transform::rgb_color::RGB;
+use crate::libs::color_url;
+use crate::libs::history::History;
+use crate::services::keyboard::{KeyboardService, KeyboardTask};
 
 use crate::constants::{MAX_H, MAX_SVL};
 
 #[derive(Serialize, Deserialize)]
 pub enum CurrentColorRequest {
   UpdateColor(Color),
+  // responds with the current color to the sender only, it has to be connected through a bridge
+  Query,
+  Undo,
+  Redo,
+  // colors updated between these two are undone as a single step
+  BeginDrag,
+  EndDrag,
+}
+
+pub enum Msg {
+  KeyDown(KeyboardEvent),
+  HashChange,
 }
 
+// how many colors can be undone
+const HISTORY_LIMIT: usize = 100;
+
+// the models are formatted by the components which show them
 #[derive(Serialize, Deserialize)]
 pub struct Response {
-  pub hex: String,
-  pub rgb: String,
-  pub cmyk: String,
-  pub hsl: String,
-  pub hsv: String,
-  pub top_right_corner: String,
-  pub hue: f32,
-  pub saturation: f32,
-  pub value: f32,
+  pub color: Color,
 }
 
 impl Response {
   fn new(color: &Color) -> Response {
-    let top_right_hsv = HSV::from_values(color.get_hue(), MAX_SVL, MAX_SVL);
-    let top_right_rgb = RGB::from_hsv(&top_right_hsv);
-
     Response {
-      hex: color.hex_value(),
-      rgb: color.rgb_value(),
-      cmyk: color.cmyk_value(),
-      hsl: color.hsl_value(),
-      hsv: color.hsv_value(),
-      top_right_corner: top_right_rgb.to_color_string(),
-      hue: color.get_hue(),
-      saturation: color.get_saturation(),
-      value: color.get_value(),
+      color: color.clone(),
     }
   }
 }
 
 pub struct CurrentColorAgent {
   color: Color,
+  history: History<Color>,
+  // the page URL is only updated once a drag is over, browsers throttle frequent navigation
+  dragging: bool,
   link: AgentLink<CurrentColorAgent>,
   subscribers: HashSet<HandlerId>,
+  _keydown_task: KeyboardTask,
+  _hashchange_listener: EventListener,
 }
 
 impl CurrentColorAgent {
-  fn send_to_subscribers(&mut self, color: &Color) {
+  fn set_color(&mut self, color: Color) {
     for sub in self.subscribers.iter() {
-      let response = Response::new(color);
+      let response = Response::new(&color);
       self.link.respond(*sub, response);
     }
+
+    self.color = color;
+
+    if !self.dragging {
+      self.update_url();
+    }
+  }
+
+  fn initial_color() -> Color {
+    let location = window().location();
+    let search = location.search().unwrap_or_default();
+    let hash = location.hash().unwrap_or_default();
+
+    if let Some(color) = color_url::parse_url_color(&search, &hash) {
+      return color;
+    }
+
+    // random color when the link has none
+    let mut rng = rand::thread_rng();
+    let hue: f32 = rand::random::<f32>() * MAX_H;
+    let saturation: f32 = rng.gen::<f32>() * MAX_SVL;
+    let value: f32 = rng.gen::<f32>() * MAX_SVL;
+
+    Color::from_hsv_values(hue.round(), saturation.round(), value.round())
+  }
+
+  // replaces the hash without a new entry in the browser history
+  fn update_url(&self) {
+    let _ = window()
+      .location()
+      .replace(&color_url::url_hash(&self.color));
+  }
+
+  // a link edited by hand or the back button, the hash written by update_url is skipped
+  fn handle_hash_change(&mut self) {
+    let hash = window().location().hash().unwrap_or_default();
+    if hash == color_url::url_hash(&self.color) {
+      return;
+    }
+
+    if let Some(color) = color_url::parse_url_color("", &hash) {
+      self.history.record(color.clone());
+      self.set_color(color);
+    }
+  }
+
+  // Ctrl+Z and Ctrl+Shift+Z (Cmd on macOS), text fields keep their own undo
+  fn handle_key_down(&mut self, event: KeyboardEvent) {
+    if !(event.ctrl_key() || event.meta_key()) || !event.key().eq_ignore_ascii_case("z") {
+      return;
+    }
+
+    let tag_name = event
+      .target()
+      .and_then(|target| target.dyn_into::<Element>().ok())
+      .map(|element| element.tag_name());
+
+    if matches!(tag_name.as_deref(), Some("INPUT") | Some("TEXTAREA")) {
+      return;
+    }
+
+    event.prevent_default();
+
+    let request = if event.shift_key() {
+      CurrentColorRequest::Redo
+    } else {
+      CurrentColorRequest::Undo
+    };
+    self.link.send_input(request);
   }
 }
 
 impl Agent for CurrentColorAgent {
   type Reach = Context<Self>;
-  type Message = ();
+  type Message = Msg;
   type Input = CurrentColorRequest;
   type Output = Response;
 
   fn create(link: AgentLink<Self>) -> Self {
-    // default color
-    let mut rng = rand::thread_rng();
-    let hue: f32 = rand::random::<f32>() * MAX_H;
-    let saturation: f32 = rng.gen::<f32>() * MAX_SVL;
-    let value: f32 = rng.gen::<f32>() * MAX_SVL;
+    let color = CurrentColorAgent::initial_color();
+
+    let keydown_callback = link.callback(Msg::KeyDown);
+    let _keydown_task = KeyboardService::new(String::from("keydown")).register(keydown_callback);
 
-    let color = Color::from_hsv_values(hue.round(), saturation.round(), value.round());
+    let hashchange_callback = link.callback(|_| Msg::HashChange);
+    let _hashchange_listener = EventListener::new(&window(), "hashchange", move |_| {
+      hashchange_callback.emit(())
+    });
 
     CurrentColorAgent {
+      history: History::new(color.clone(), HISTORY_LIMIT),
+      dragging: false,
       color,
       link,
       subscribers: HashSet::new(),
+      _keydown_task,
+      _hashchange_listener,
     }
   }
 
-  fn update(&mut self, _: Self::Message) {}
+  fn update(&mut self, msg: Self::Message) {
+    match msg {
+      Msg::KeyDown(event) => self.handle_key_down(event),
+      Msg::HashChange => self.handle_hash_change(),
+    }
+  }
 
-  fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
+  fn handle_input(&mut self, msg: Self::Input, id: HandlerId) {
     match msg {
       CurrentColorRequest::UpdateColor(color) => {
-        self.send_to_subscribers(&color);
+        self.history.record(color.clone());
+        self.set_color(color);
+      }
+
+      CurrentColorRequest::Query => {
+        if id.is_respondable() {
+          self.link.respond(id, Response::new(&self.color));
+        }
+      }
+
+      CurrentColorRequest::Undo => {
+        if let Some(color) = self.history.undo().cloned() {
+          self.set_color(color);
+        }
+      }
+
+      CurrentColorRequest::Redo => {
+        if let Some(color) = self.history.redo().cloned() {
+          self.set_color(color);
+        }
+      }
+
+      CurrentColorRequest::BeginDrag => {
+        self.dragging = true;
+        self.history.begin_coalescing();
+      }
+
+      CurrentColorRequest::EndDrag => {
+        self.dragging = false;
+        self.history.end_coalescing();
+        self.update_url();
       }
     }
   }
 
+  // components mounted later get the latest color right away
   fn connected(&mut self, id: HandlerId) {
-    self.subscribers.insert(id);
+    if !id.is_respondable() {
+      return;
+    }
 
-    let initial_value = Response::new(&self.color);
-    self.link.respond(id, initial_value);
+    self.subscribers.insert(id);
+    self.link.respond(id, Response::new(&self.color));
   }
 
   fn disconnected(&mut self, id: HandlerId) {
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use yew::worker::{Agent, AgentLink, Context, HandlerId};
+
+use crate::libs::cvd::Simulation;
+
+#[derive(Serialize, Deserialize)]
+pub enum CvdRequest {
+  // None turns the simulation off
+  SetSimulation(Option<Simulation>),
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CvdResponse {
+  pub simulation: Option<Simulation>,
+}
+
+pub struct CvdAgent {
+  simulation: Option<Simulation>,
+  link: AgentLink<CvdAgent>,
+  subscribers: HashSet<HandlerId>,
+}
+
+impl Agent for CvdAgent {
+  type Reach = Context<Self>;
+  type Message = ();
+  type Input = CvdRequest;
+  type Output = CvdResponse;
+
+  fn create(link: AgentLink<Self>) -> Self {
+    CvdAgent {
+      simulation: None,
+      link,
+      subscribers: HashSet::new(),
+    }
+  }
+
+  fn update(&mut self, _: Self::Message) {}
+
+  fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
+    match msg {
+      CvdRequest::SetSimulation(simulation) => {
+        self.simulation = simulation;
+
+        for sub in self.subscribers.iter() {
+          self.link.respond(*sub, CvdResponse { simulation });
+        }
+      }
+    }
+  }
+
+  fn connected(&mut self, id: HandlerId) {
+    if !id.is_respondable() {
+      return;
+    }
+
+    self.subscribers.insert(id);
+    self.link.respond(
+      id,
+      CvdResponse {
+        simulation: self.simulation,
+      },
+    );
+  }
+
+  fn disconnected(&mut self, id: HandlerId) {
+    self.subscribers.remove(&id);
+  }
+}
@@ -32,11 +32,12 @@ impl Agent for HslColorAgent {
   fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
     match msg {
       Request::HslColorChangeMsg(hsl) => {
-        if color_validate::is_valid_hsv(&hsl) {
-          let color = Color::from_hsl(hsl);
-          self
-            .current_color_dispatcher
-            .send(CurrentColorRequest::UpdateColor(color));
+        if color_validate::is_valid_hsl(&hsl) {
+          if let Ok(color) = Color::from_hsl(hsl) {
+            self
+              .current_color_dispatcher
+              .send(CurrentColorRequest::UpdateColor(color));
+          }
         }
       }
     }
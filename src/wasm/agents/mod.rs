@@ -1,6 +1,11 @@
 pub mod cmyk_color_agent;
 pub mod current_color_agent;
+pub mod cvd_agent;
 pub mod hex_color_agent;
 pub mod hsl_color_agent;
 pub mod hsv_color_agent;
+pub mod lab_color_agent;
+pub mod lch_color_agent;
+pub mod oklch_color_agent;
+pub mod palette_agent;
 pub mod rgb_color_agent;
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use yew::agent::{Agent, AgentLink, Bridge, Bridged, Context, Dispatched, Dispatcher, HandlerId};
+
+use crate::agents::current_color_agent::{
+  CurrentColorAgent, CurrentColorRequest, Response as CurrentColorResponse,
+};
+use crate::libs::color_transform::Color;
+use crate::libs::palette::storage::LocalStorage;
+use crate::libs::palette::{swatch_name, Palette, SwatchGroup};
+use crate::texts::TEXTS;
+
+const STORAGE_KEY: &str = "color-picker-palette";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PaletteRequest {
+  AddGroup(String),
+  RemoveGroup(usize),
+  RenameGroup(usize, String),
+  // group and the name of the swatch, the hex value is used for an empty name
+  AddCurrentColor(usize, String),
+  RemoveSwatch(usize, usize),
+  // group, from and to indexes
+  MoveSwatch(usize, usize, usize),
+  SelectSwatch(usize, usize),
+  // groups read from a swatch file, added after the existing ones
+  ImportGroups(Vec<SwatchGroup>),
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PaletteResponse {
+  pub groups: Vec<SwatchGroup>,
+}
+
+pub enum Msg {
+  CurrentColorChange(CurrentColorResponse),
+}
+
+pub struct PaletteAgent {
+  palette: Palette,
+  color: Option<Color>,
+  link: AgentLink<PaletteAgent>,
+  subscribers: HashSet<HandlerId>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl PaletteAgent {
+  fn response(&self) -> PaletteResponse {
+    PaletteResponse {
+      groups: self.palette.get_groups().to_vec(),
+    }
+  }
+
+  fn send_to_subscribers(&self) {
+    for sub in self.subscribers.iter() {
+      self.link.respond(*sub, self.response());
+    }
+  }
+}
+
+impl Agent for PaletteAgent {
+  type Reach = Context<Self>;
+  type Message = Msg;
+  type Input = PaletteRequest;
+  type Output = PaletteResponse;
+
+  fn create(link: AgentLink<Self>) -> Self {
+    let callback = link.callback(Msg::CurrentColorChange);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    let storage = Box::new(LocalStorage::new(STORAGE_KEY));
+
+    PaletteAgent {
+      palette: Palette::load(storage, TEXTS.palette_default_group),
+      color: None,
+      link,
+      subscribers: HashSet::new(),
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) {
+    match msg {
+      Msg::CurrentColorChange(response) => {
+        self.color = Some(response.color);
+      }
+    }
+  }
+
+  fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
+    let changed = match msg {
+      PaletteRequest::AddGroup(name) => {
+        self.palette.add_group(name);
+        true
+      }
+
+      PaletteRequest::RemoveGroup(group) => self.palette.remove_group(group),
+
+      PaletteRequest::RenameGroup(group, name) => self.palette.rename_group(group, name),
+
+      PaletteRequest::AddCurrentColor(group, name) => match &self.color {
+        Some(color) => self
+          .palette
+          .add_swatch(group, swatch_name(&name, color), color.clone())
+          .is_some(),
+        None => false,
+      },
+
+      PaletteRequest::RemoveSwatch(group, index) => self.palette.remove_swatch(group, index),
+
+      PaletteRequest::MoveSwatch(group, from, to) => self.palette.move_swatch(group, from, to),
+
+      PaletteRequest::SelectSwatch(group, index) => {
+        if let Some(swatch) = self.palette.get_swatch(group, index) {
+          self
+            .current_color_dispatcher
+            .send(CurrentColorRequest::UpdateColor(swatch.color.clone()));
+        }
+
+        false
+      }
+
+      PaletteRequest::ImportGroups(groups) => {
+        let changed = !groups.is_empty();
+        self.palette.add_groups(groups);
+        changed
+      }
+    };
+
+    if changed {
+      self.send_to_subscribers();
+    }
+  }
+
+  fn connected(&mut self, id: HandlerId) {
+    self.subscribers.insert(id);
+    self.link.respond(id, self.response());
+  }
+
+  fn disconnected(&mut self, id: HandlerId) {
+    self.subscribers.remove(&id);
+  }
+}
@@ -5,6 +5,7 @@ use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest}
 use crate::libs::color_transform::Color;
 
 use crate::libs::color_validate;
+use crate::libs::css_color;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub enum Request {
@@ -32,8 +33,14 @@ impl Agent for RgbColorAgent {
   fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
     match msg {
       Request::RgbColorChangeMsg(rgb) => {
-        if color_validate::is_valid_rgb(&rgb) {
-          let color = Color::from_rgb(rgb);
+        // anything else a browser accepts is read as a CSS color
+        let color = if color_validate::is_valid_rgb(&rgb) {
+          Color::from_rgb(rgb)
+        } else {
+          css_color::parse(&rgb)
+        };
+
+        if let Ok(color) = color {
           self
             .current_color_dispatcher
             .send(CurrentColorRequest::UpdateColor(color));
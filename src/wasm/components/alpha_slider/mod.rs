@@ -0,0 +1,214 @@
+use web_sys::{Element, HtmlElement, MouseEvent};
+use yew::callback::Callback;
+use yew::{html, Component, ComponentLink, Html, NodeRef, Properties, ShouldRender};
+
+use crate::libs::color_transform::Color;
+use crate::services::mouse::{MouseService, MouseTask};
+
+use crate::constants::{MAX_ALPHA, MIN_ALPHA};
+
+#[derive(Clone, Properties)]
+pub struct Props {
+  pub value: Color,
+  #[prop_or_default]
+  pub on_change: Callback<Color>,
+  // colors emitted between these two come from a single drag
+  #[prop_or_default]
+  pub on_drag_start: Callback<()>,
+  #[prop_or_default]
+  pub on_drag_end: Callback<()>,
+}
+
+pub enum Msg {
+  MouseDown(MouseEvent),
+  MouseMove(MouseEvent),
+  MouseUp(MouseEvent),
+  MouseOut(MouseEvent),
+  SliderClick(MouseEvent),
+}
+
+struct Tasks {
+  _mouse_move: MouseTask,
+  _mouse_up: MouseTask,
+  _mouse_out: MouseTask,
+}
+
+struct SliderData {
+  alpha: f32,
+  start: i32,
+}
+
+impl Tasks {
+  fn new(_mouse_move: MouseTask, _mouse_up: MouseTask, _mouse_out: MouseTask) -> Tasks {
+    Tasks {
+      _mouse_move,
+      _mouse_up,
+      _mouse_out,
+    }
+  }
+}
+
+pub struct AlphaSlider {
+  props: Props,
+  link: ComponentLink<AlphaSlider>,
+  start_data: Option<SliderData>,
+  _tasks: Tasks,
+  slider_ref: NodeRef,
+}
+
+impl AlphaSlider {
+  // opacity is kept with two decimals, the same precision it is printed with
+  fn round_alpha(alpha: f32) -> f32 {
+    let alpha = (alpha * 100.0).round() / 100.0;
+    alpha.clamp(MIN_ALPHA, MAX_ALPHA)
+  }
+
+  fn change_alpha(&self, alpha: f32) {
+    let color = self.props.value.with_alpha(f64::from(alpha));
+    self.props.on_change.emit(color);
+  }
+
+  fn handle_mouse_down(&mut self, event: MouseEvent) {
+    self.props.on_drag_start.emit(());
+
+    self.start_data = Some(SliderData {
+      start: event.screen_x(),
+      alpha: self.props.value.get_alpha(),
+    });
+  }
+
+  fn handle_mouse_move(&mut self, event: MouseEvent) {
+    if let Some(start_data) = &self.start_data {
+      let diff = event.screen_x() - start_data.start;
+      let slider_width = self
+        .slider_ref
+        .cast::<HtmlElement>()
+        .unwrap()
+        .offset_width();
+
+      let alpha_diff = diff as f32 / slider_width as f32 * MAX_ALPHA;
+      let alpha = AlphaSlider::round_alpha(start_data.alpha + alpha_diff);
+
+      self.change_alpha(alpha);
+    }
+  }
+
+  fn handle_mouse_up(&mut self, _: MouseEvent) {
+    if self.start_data.take().is_some() {
+      self.props.on_drag_end.emit(());
+    }
+  }
+
+  fn handle_slider_click(&mut self, event: MouseEvent) {
+    self.props.on_drag_start.emit(());
+
+    let x = event.client_x();
+    let left = self
+      .slider_ref
+      .cast::<Element>()
+      .unwrap()
+      .get_bounding_client_rect()
+      .left() as i32;
+
+    let slider_width = self
+      .slider_ref
+      .cast::<HtmlElement>()
+      .unwrap()
+      .offset_width();
+
+    let alpha = (x - left) as f32 / slider_width as f32 * MAX_ALPHA;
+    let alpha = AlphaSlider::round_alpha(alpha);
+
+    self.change_alpha(alpha);
+    self.start_data = Some(SliderData { start: x, alpha });
+  }
+}
+
+impl Component for AlphaSlider {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> AlphaSlider {
+    let move_callback = link.callback(Msg::MouseMove);
+    let up_callback = link.callback(Msg::MouseUp);
+    let out_callback = link.callback(Msg::MouseOut);
+
+    let _mousemove_task = MouseService::new(String::from("mousemove")).register(move_callback);
+    let _mouseup_task = MouseService::new(String::from("mouseup")).register(up_callback);
+    let _mouseout_task = MouseService::new(String::from("mouseleave")).register(out_callback);
+
+    let _tasks = Tasks::new(_mousemove_task, _mouseup_task, _mouseout_task);
+
+    AlphaSlider {
+      props,
+      link,
+      _tasks,
+      start_data: None,
+      slider_ref: NodeRef::default(),
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    self.props = props;
+    true
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::MouseDown(event) => {
+        self.handle_mouse_down(event);
+        false
+      }
+
+      Msg::MouseMove(event) => {
+        self.handle_mouse_move(event);
+        false
+      }
+
+      Msg::MouseUp(event) => {
+        self.handle_mouse_up(event);
+        false
+      }
+
+      Msg::MouseOut(event) => {
+        self.handle_mouse_up(event);
+        false
+      }
+
+      Msg::SliderClick(event) => {
+        self.handle_slider_click(event);
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    let left = self.props.value.get_alpha() / MAX_ALPHA * 100.0;
+    let opaque_color = self.props.value.opaque_color_value();
+
+    html! {
+      <div class="slider">
+        <div
+          ref={self.slider_ref.clone()}
+          class="slider__alpha"
+          onmousedown={self.link.callback(Msg::SliderClick)}
+        >
+          <div
+            class="slider__alpha-gradient"
+            style={format!("background: linear-gradient(to right, transparent, {});", opaque_color)}
+          />
+        </div>
+        <div
+          class="slider__selector slider__selector--alpha"
+          style={format!("left: {}%;", left)}
+          onmousedown={self.link.callback(Msg::MouseDown)}
+        >
+          <div
+            class="slider__selector-color"
+            style={format!("background-color: {};", self.props.value.hex_value())}
+          />
+        </div>
+      </div>
+    }
+  }
+}
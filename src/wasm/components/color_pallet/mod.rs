@@ -1,17 +1,31 @@
 use web_sys::{Element, HtmlElement, MouseEvent};
-use yew::agent::{Dispatched, Dispatcher};
-use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, NodeRef, ShouldRender};
+use yew::callback::Callback;
+use yew::{html, Component, ComponentLink, Html, NodeRef, Properties, ShouldRender};
 
 use pallet_canvas::PalletCanvas;
 
-use crate::agents::current_color_agent::{CurrentColorAgent, Response};
-use crate::agents::hsv_color_agent::{HsvColorAgent, Request};
+use crate::libs::color_transform::Color;
+use crate::libs::cvd::{self, Simulation};
 use crate::services::mouse::{MouseService, MouseTask};
 
 use crate::constants::{MAX_SVL, MIN_HSV};
 
 pub mod pallet_canvas;
 
+#[derive(Clone, Properties)]
+pub struct Props {
+  pub value: Color,
+  #[prop_or_default]
+  pub simulation: Option<Simulation>,
+  #[prop_or_default]
+  pub on_change: Callback<Color>,
+  // colors emitted between these two come from a single drag
+  #[prop_or_default]
+  pub on_drag_start: Callback<()>,
+  #[prop_or_default]
+  pub on_drag_end: Callback<()>,
+}
+
 struct SliderData {
   saturation: f32,
   value: f32,
@@ -36,20 +50,15 @@ impl Tasks {
 }
 
 pub struct ColorPallet {
-  color: String,
-  saturation: f32,
-  value: f32,
+  props: Props,
   link: ComponentLink<ColorPallet>,
-  hsv_color_agent: Dispatcher<HsvColorAgent>,
-  _producer: Box<dyn Bridge<CurrentColorAgent>>,
   pallet_ref: NodeRef,
   start_data: Option<SliderData>,
   _tasks: Tasks,
 }
 
 pub enum Msg {
-  CurrentColorMessage(Response),
-  MouseDown(MouseEvent),
+  PalletClick(MouseEvent),
   MouseMove(MouseEvent),
   MouseUp(MouseEvent),
   MouseOut(MouseEvent),
@@ -83,7 +92,19 @@ impl ColorPallet {
     )
   }
 
-  fn handle_mouse_down(&mut self, event: MouseEvent) {
+  // hue and opacity are kept from the current value
+  fn change_color(&self, saturation: f32, value: f32) {
+    let [hue, ..] = self.props.value.hsva_values();
+    let color = self
+      .props
+      .value
+      .with_hsv(hue, f64::from(saturation), f64::from(value));
+    self.props.on_change.emit(color);
+  }
+
+  fn handle_pallet_click(&mut self, event: MouseEvent) {
+    self.props.on_drag_start.emit(());
+
     let (saturation, value) = self.get_color_values(event.client_x(), event.client_y());
 
     self.start_data = Some(SliderData {
@@ -92,9 +113,7 @@ impl ColorPallet {
       saturation,
       value,
     });
-    self
-      .hsv_color_agent
-      .send(Request::SaturationValueChangedMsg(saturation, value));
+    self.change_color(saturation, value);
   }
 
   fn handle_mouse_move(&mut self, event: MouseEvent) {
@@ -119,27 +138,22 @@ impl ColorPallet {
       let value = start_data.value - value_diff;
       let value = (value.max(MIN_HSV)).min(MAX_SVL);
 
-      self
-        .hsv_color_agent
-        .send(Request::SaturationValueChangedMsg(saturation, value));
+      self.change_color(saturation, value);
     }
   }
 
   fn handle_mouse_up(&mut self, _: MouseEvent) {
-    self.start_data = None;
+    if self.start_data.take().is_some() {
+      self.props.on_drag_end.emit(());
+    }
   }
 }
 
 impl Component for ColorPallet {
   type Message = Msg;
-  type Properties = ();
-
-  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
-    let callback = link.callback(Msg::CurrentColorMessage);
-
-    let hsv_color_agent = HsvColorAgent::dispatcher();
-    let _producer = CurrentColorAgent::bridge(callback);
+  type Properties = Props;
 
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
     let move_callback = link.callback(|e: MouseEvent| Msg::MouseMove(e));
     let up_callback = link.callback(|e: MouseEvent| Msg::MouseUp(e));
     let out_callback = link.callback(|e: MouseEvent| Msg::MouseOut(e));
@@ -151,34 +165,23 @@ impl Component for ColorPallet {
     let _tasks = Tasks::new(_mousemove_task, _mouseup_task, _mouseout_task);
 
     ColorPallet {
-      color: String::from(""),
-      saturation: 0.0,
-      value: 0.0,
-      hsv_color_agent,
+      props,
       link,
       pallet_ref: NodeRef::default(),
       start_data: None,
-      _producer,
       _tasks,
     }
   }
 
-  fn change(&mut self, _: Self::Properties) -> bool {
-    false
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    self.props = props;
+    true
   }
 
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
-      Msg::CurrentColorMessage(response) => {
-        self.color = response.hex;
-        self.saturation = response.saturation;
-        self.value = response.value;
-
-        true
-      }
-
-      Msg::MouseDown(event) => {
-        self.handle_mouse_down(event);
+      Msg::PalletClick(event) => {
+        self.handle_pallet_click(event);
         false
       }
 
@@ -200,22 +203,23 @@ impl Component for ColorPallet {
   }
 
   fn view(&self) -> Html {
-    let left = self.saturation as f32 / MAX_SVL as f32 * 100.0;
-    let bottom = self.value as f32 / MAX_SVL as f32 * 100.0;
+    let value = &self.props.value;
+    let left = value.get_saturation() / MAX_SVL * 100.0;
+    let bottom = value.get_value() / MAX_SVL * 100.0;
+
+    let color = cvd::seen_as(value, self.props.simulation).hex_value();
 
     html! {
-      <div class="colors__pallet">
+      <div
+        class="pallet"
+        ref={self.pallet_ref.clone()}
+        onmousedown={self.link.callback(|e: MouseEvent| Msg::PalletClick(e))}
+      >
+        <PalletCanvas hue={value.get_hue()} simulation={self.props.simulation} />
         <div
-          class="pallet"
-          ref={self.pallet_ref.clone()}
-          onmousedown={self.link.callback(|e: MouseEvent| Msg::MouseDown(e))}
-        >
-          <PalletCanvas />
-          <div
-            class="pallet__selector"
-            style={format!("background-color: {}; bottom: {}%; left: {}%;", self.color, bottom, left)}
-          />
-        </div>
+          class="pallet__selector"
+          style={format!("background-color: {}; bottom: {}%; left: {}%;", color, bottom, left)}
+        />
       </div>
     }
   }
@@ -1,21 +1,26 @@
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
-use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, NodeRef, ShouldRender};
+use yew::{html, Component, ComponentLink, Html, NodeRef, Properties, ShouldRender};
 
-use crate::agents::current_color_agent::{CurrentColorAgent, Response};
-
-use crate::constants::MAX_SVL;
+use crate::constants::{MAX_ALPHA, MAX_SVL};
 use crate::libs::color_transform::hsv_color::HSV;
 use crate::libs::color_transform::rgb_color::RGB;
-
-pub enum Msg {
-  CurrentColorMessage(Response),
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::CssFormat;
+use crate::libs::cvd::Simulation;
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+  pub hue: f32,
+  // draws the pallet the way it is seen with a color vision deficiency
+  #[prop_or_default]
+  pub simulation: Option<Simulation>,
 }
 
 pub struct PalletCanvas {
   canvas_ref: NodeRef,
   hue: f32,
-  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+  simulation: Option<Simulation>,
 }
 
 impl PalletCanvas {
@@ -37,10 +42,24 @@ impl PalletCanvas {
 
     for i in 0..height {
       for j in 0..width {
-        let hsv = HSV::from_values(self.hue, j as f32, (height - i) as f32);
-        let rgb = RGB::from_hsv(&hsv);
-
-        ctx.set_fill_style(&JsValue::from_str(&rgb.to_color_string()));
+        let color = match &self.simulation {
+          Some(simulation) => {
+            let color = Color::from_hsv_values(self.hue, j as f32, (height - i) as f32);
+            simulation.apply(&color).to_css_string(CssFormat::Rgb)
+          }
+
+          None => {
+            let hsv = HSV::from_values(
+              f64::from(self.hue),
+              f64::from(j),
+              f64::from(height - i),
+              f64::from(MAX_ALPHA),
+            );
+            RGB::from_hsv(&hsv).to_color_string()
+          }
+        };
+
+        ctx.set_fill_style(&JsValue::from_str(&color));
         ctx.fill_rect(j as f64, i as f64, 1.0, 1.0);
       }
     }
@@ -48,34 +67,34 @@ impl PalletCanvas {
 }
 
 impl Component for PalletCanvas {
-  type Message = Msg;
-  type Properties = ();
-
-  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
-    let callback = link.callback(Msg::CurrentColorMessage);
-    let _producer = CurrentColorAgent::bridge(callback);
+  type Message = ();
+  type Properties = Props;
 
+  fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
     PalletCanvas {
       canvas_ref: NodeRef::default(),
-      hue: 0.0,
-      _producer,
+      hue: props.hue,
+      simulation: props.simulation,
     }
   }
 
-  fn change(&mut self, _: Self::Properties) -> bool {
+  fn change(&mut self, props: Self::Properties) -> bool {
+    if self.hue != props.hue || self.simulation != props.simulation {
+      self.hue = props.hue;
+      self.simulation = props.simulation;
+      self.redraw_canvas();
+    }
+
     false
   }
 
-  fn update(&mut self, msg: Self::Message) -> ShouldRender {
-    match msg {
-      Msg::CurrentColorMessage(response) => {
-        if self.hue != response.hue {
-          self.hue = response.hue;
-          self.redraw_canvas();
-        }
+  fn update(&mut self, _: Self::Message) -> ShouldRender {
+    false
+  }
 
-        false
-      }
+  fn rendered(&mut self, first_render: bool) {
+    if first_render {
+      self.redraw_canvas();
     }
   }
 
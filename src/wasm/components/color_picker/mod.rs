@@ -0,0 +1,194 @@
+use yew::callback::Callback;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+
+use crate::components::alpha_slider::AlphaSlider;
+use crate::components::color_pallet::ColorPallet;
+use crate::components::color_slider::ColorSlider;
+use crate::components::values::color_input::ColorInput;
+use crate::libs::color_transform::parse_error::ParseColorError;
+use crate::libs::color_transform::Color;
+use crate::texts::TEXTS;
+
+use crate::constants::MAX_SVL;
+
+/// Color models the picker shows an input for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorModel {
+  Hex,
+  Rgb,
+  Cmyk,
+  Hsv,
+  Hsl,
+  Lab,
+  Lch,
+  Oklch,
+}
+
+impl ColorModel {
+  fn title(self) -> &'static str {
+    match self {
+      ColorModel::Hex => TEXTS.hex,
+      ColorModel::Rgb => TEXTS.rgb,
+      ColorModel::Cmyk => TEXTS.cmyk,
+      ColorModel::Hsv => TEXTS.hsv,
+      ColorModel::Hsl => TEXTS.hsl,
+      ColorModel::Lab => TEXTS.lab,
+      ColorModel::Lch => TEXTS.lch,
+      ColorModel::Oklch => TEXTS.oklch,
+    }
+  }
+
+  fn value(self, color: &Color) -> String {
+    match self {
+      ColorModel::Hex => color.hex_value(),
+      ColorModel::Rgb => color.rgb_value(),
+      ColorModel::Cmyk => color.cmyk_value(),
+      ColorModel::Hsv => color.hsv_value(),
+      ColorModel::Hsl => color.hsl_value(),
+      ColorModel::Lab => color.lab_value(),
+      ColorModel::Lch => color.lch_value(),
+      ColorModel::Oklch => color.oklch_value(),
+    }
+  }
+
+  fn parse(self, value: String) -> Result<Color, ParseColorError> {
+    match self {
+      ColorModel::Hex => Color::from_hex(value),
+      ColorModel::Rgb => Color::from_rgb(value),
+      ColorModel::Cmyk => Color::from_cmyk(value),
+      ColorModel::Hsv => Color::from_hsv(value),
+      ColorModel::Hsl => Color::from_hsl(value),
+      ColorModel::Lab => Color::from_lab(value),
+      ColorModel::Lch => Color::from_lch(value),
+      ColorModel::Oklch => Color::from_oklch(value),
+    }
+  }
+}
+
+fn all_models() -> Vec<ColorModel> {
+  vec![
+    ColorModel::Hex,
+    ColorModel::Rgb,
+    ColorModel::Cmyk,
+    ColorModel::Hsv,
+    ColorModel::Hsl,
+    ColorModel::Lab,
+    ColorModel::Lch,
+    ColorModel::Oklch,
+  ]
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+  // anything Color::parse understands, an invalid value keeps the previous color
+  // and red is used until the first valid one
+  #[prop_or_default]
+  pub value: String,
+  #[prop_or_else(all_models)]
+  pub models: Vec<ColorModel>,
+  #[prop_or_default]
+  pub on_change: Callback<Color>,
+}
+
+pub enum Msg {
+  ColorChanged(Color),
+  ValueChanged(ColorModel, String),
+}
+
+/// Self-contained picker for embedding into other Yew apps, every instance keeps its own color
+/// instead of sharing the global agents the rest of the app talks through.
+pub struct ColorPicker {
+  color: Color,
+  value: String,
+  models: Vec<ColorModel>,
+  on_change: Callback<Color>,
+  link: ComponentLink<ColorPicker>,
+}
+
+impl ColorPicker {
+  fn parse_value(value: &str) -> Option<Color> {
+    Color::parse(value).ok()
+  }
+
+  fn set_color(&mut self, color: Color) {
+    self.on_change.emit(color.clone());
+    self.color = color;
+  }
+
+  fn view_value(&self, model: ColorModel) -> Html {
+    html! {
+      <div class="value-color">
+        <span class="value-color__title">
+          {model.title()}
+        </span>
+        <ColorInput
+          class="value-color__input"
+          value={model.value(&self.color)}
+          on_change={self.link.callback(move |value| Msg::ValueChanged(model, value))}
+        />
+      </div>
+    }
+  }
+}
+
+impl Component for ColorPicker {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let color = ColorPicker::parse_value(&props.value)
+      .unwrap_or_else(|| Color::from_hsv_values(0.0, MAX_SVL, MAX_SVL));
+
+    ColorPicker {
+      color,
+      value: props.value,
+      models: props.models,
+      on_change: props.on_change,
+      link,
+    }
+  }
+
+  // a new value from the parent replaces the color without calling on_change
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.value != props.value {
+      if let Some(color) = ColorPicker::parse_value(&props.value) {
+        self.color = color;
+      }
+      self.value = props.value;
+    }
+
+    self.models = props.models;
+    self.on_change = props.on_change;
+    true
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::ColorChanged(color) => self.set_color(color),
+
+      Msg::ValueChanged(model, value) => match model.parse(value) {
+        Ok(color) => self.set_color(color),
+        Err(_) => return false,
+      },
+    }
+
+    true
+  }
+
+  fn view(&self) -> Html {
+    let on_change = self.link.callback(Msg::ColorChanged);
+
+    html! {
+      <div class="embedded-picker">
+        <div class="embedded-picker__pallet">
+          <ColorPallet value={self.color.clone()} on_change={on_change.clone()} />
+        </div>
+        <ColorSlider value={self.color.clone()} on_change={on_change.clone()} />
+        <AlphaSlider value={self.color.clone()} on_change={on_change} />
+        <div class="values-container">
+          {for self.models.iter().map(|model| self.view_value(*model))}
+        </div>
+      </div>
+    }
+  }
+}
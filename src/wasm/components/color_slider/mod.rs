@@ -1,15 +1,31 @@
 use web_sys::{Element, HtmlElement, MouseEvent};
-use yew::agent::{Dispatched, Dispatcher};
-use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, NodeRef, ShouldRender};
+use yew::callback::Callback;
+use yew::{html, Component, ComponentLink, Html, NodeRef, Properties, ShouldRender};
 
-use crate::agents::current_color_agent::{CurrentColorAgent, Response};
-use crate::agents::hsv_color_agent::{HsvColorAgent, Request};
+use crate::libs::color_transform::Color;
+use crate::libs::cvd::{self, Simulation};
 use crate::services::mouse::{MouseService, MouseTask};
 
-use crate::constants::{MAX_H, MIN_HSV};
+use crate::constants::{MAX_H, MAX_SVL, MIN_HSV};
+
+// stops of the simulated hue track, one per 30 degrees
+const HUE_STOPS: u16 = 12;
+
+#[derive(Clone, Properties)]
+pub struct Props {
+  pub value: Color,
+  #[prop_or_default]
+  pub simulation: Option<Simulation>,
+  #[prop_or_default]
+  pub on_change: Callback<Color>,
+  // colors emitted between these two come from a single drag
+  #[prop_or_default]
+  pub on_drag_start: Callback<()>,
+  #[prop_or_default]
+  pub on_drag_end: Callback<()>,
+}
 
 pub enum Msg {
-  CurrentColorMessage(Response),
   MouseDown(MouseEvent),
   MouseMove(MouseEvent),
   MouseUp(MouseEvent),
@@ -39,21 +55,27 @@ impl Tasks {
 }
 
 pub struct ColorSlider {
-  color: String,
-  hue: f32,
+  props: Props,
   link: ComponentLink<ColorSlider>,
-  hsv_color_agent: Dispatcher<HsvColorAgent>,
-  _producer: Box<dyn Bridge<CurrentColorAgent>>,
   start_data: Option<SliderData>,
   _tasks: Tasks,
   slider_ref: NodeRef,
 }
 
 impl ColorSlider {
+  // saturation, value and opacity are kept from the current value
+  fn change_hue(&self, hue: f32) {
+    let [_, saturation, value, _] = self.props.value.hsva_values();
+    let color = self.props.value.with_hsv(f64::from(hue), saturation, value);
+    self.props.on_change.emit(color);
+  }
+
   fn handle_mouse_down(&mut self, event: MouseEvent) {
+    self.props.on_drag_start.emit(());
+
     self.start_data = Some(SliderData {
       start: event.screen_x(),
-      hue: self.hue,
+      hue: self.props.value.get_hue(),
     });
   }
 
@@ -72,15 +94,44 @@ impl ColorSlider {
       let hue = start_data.hue + hue_diff;
       let hue = (hue.max(MIN_HSV)).min(MAX_H);
 
-      self.hsv_color_agent.send(Request::HueChangedMsg(hue));
+      self.change_hue(hue);
     }
   }
 
   fn handle_mouse_up(&mut self, _: MouseEvent) {
-    self.start_data = None;
+    if self.start_data.take().is_some() {
+      self.props.on_drag_end.emit(());
+    }
+  }
+
+  // fully saturated color of the hue, seen through the active simulation
+  fn hue_color(&self, hue: f32) -> String {
+    cvd::seen_as(
+      &Color::from_hsv_values(hue, MAX_SVL, MAX_SVL),
+      self.props.simulation,
+    )
+    .hex_value()
+  }
+
+  // the track is a css gradient, a simulation replaces it with the simulated hues
+  fn track_style(&self) -> String {
+    if self.props.simulation.is_none() {
+      return String::new();
+    }
+
+    let stops: Vec<String> = (0..=HUE_STOPS)
+      .map(|stop| self.hue_color(f32::from(stop) / f32::from(HUE_STOPS) * MAX_H))
+      .collect();
+
+    format!(
+      "background: linear-gradient(to right, {});",
+      stops.join(", ")
+    )
   }
 
   fn handle_slider_click(&mut self, event: MouseEvent) {
+    self.props.on_drag_start.emit(());
+
     let x = event.client_x();
     let left = self
       .slider_ref
@@ -98,21 +149,16 @@ impl ColorSlider {
     let hue = (x - left) as f32 / pallet_width as f32 * MAX_H;
     let hue = hue.round();
 
-    self.hsv_color_agent.send(Request::HueChangedMsg(hue));
+    self.change_hue(hue);
     self.start_data = Some(SliderData { start: x, hue });
   }
 }
 
 impl Component for ColorSlider {
   type Message = Msg;
-  type Properties = ();
-
-  fn create(_: Self::Properties, link: ComponentLink<Self>) -> ColorSlider {
-    let callback = link.callback(Msg::CurrentColorMessage);
-
-    let hsv_color_agent = HsvColorAgent::dispatcher();
-    let _producer = CurrentColorAgent::bridge(callback);
+  type Properties = Props;
 
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> ColorSlider {
     let move_callback = link.callback(|e: MouseEvent| Msg::MouseMove(e));
     let up_callback = link.callback(|e: MouseEvent| Msg::MouseUp(e));
     let out_callback = link.callback(|e: MouseEvent| Msg::MouseOut(e));
@@ -124,29 +170,21 @@ impl Component for ColorSlider {
     let _tasks = Tasks::new(_mousemove_task, _mouseup_task, _mouseout_task);
 
     ColorSlider {
-      color: String::from(""),
-      hue: 0.0,
+      props,
       link,
-      hsv_color_agent,
-      _producer,
       _tasks,
       start_data: None,
       slider_ref: NodeRef::default(),
     }
   }
 
-  fn change(&mut self, _: Self::Properties) -> ShouldRender {
-    false
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    self.props = props;
+    true
   }
 
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
-      Msg::CurrentColorMessage(response) => {
-        self.color = response.top_right_corner;
-        self.hue = response.hue;
-        true
-      }
-
       Msg::MouseDown(event) => {
         self.handle_mouse_down(event);
         false
@@ -175,18 +213,20 @@ impl Component for ColorSlider {
   }
 
   fn view(&self) -> Html {
-    let left = self.hue as f32 / MAX_H as f32 * 100.0;
+    let hue = self.props.value.get_hue();
+    let left = hue / MAX_H * 100.0;
 
     html! {
       <div class="slider">
         <div
           ref={self.slider_ref.clone()}
           class="slider__hue"
+          style={self.track_style()}
           onmousedown={self.link.callback(|e: MouseEvent| Msg::SliderClick(e))}
         />
         <div
           class="slider__selector"
-          style={format!("background-color: {}; left: {}%;", self.color, left)}
+          style={format!("background-color: {}; left: {}%;", self.hue_color(hue), left)}
           onmousedown={self.link.callback(|e: MouseEvent| Msg::MouseDown(e))}
         />
       </div>
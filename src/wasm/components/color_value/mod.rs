@@ -1,14 +1,20 @@
 use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
 
 use crate::agents::current_color_agent::{CurrentColorAgent, Response};
+use crate::agents::cvd_agent::{CvdAgent, CvdResponse};
+use crate::libs::color_transform::Color;
+use crate::libs::cvd::{self, Simulation};
 
 pub enum Msg {
   NewMessage(Response),
+  CvdMessage(CvdResponse),
 }
 
 pub struct ColorValue {
-  color: String,
+  color: Option<Color>,
+  simulation: Option<Simulation>,
   _producer: Box<dyn Bridge<CurrentColorAgent>>,
+  _cvd_producer: Box<dyn Bridge<CvdAgent>>,
 }
 
 impl Component for ColorValue {
@@ -18,10 +24,13 @@ impl Component for ColorValue {
   fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
     let callback = link.callback(Msg::NewMessage);
     let _producer = CurrentColorAgent::bridge(callback);
+    let _cvd_producer = CvdAgent::bridge(link.callback(Msg::CvdMessage));
 
     ColorValue {
-      color: "".to_string(),
+      color: None,
+      simulation: None,
       _producer,
+      _cvd_producer,
     }
   }
 
@@ -31,17 +40,26 @@ impl Component for ColorValue {
 
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
-      Msg::NewMessage(response) => self.color = response.hex,
+      Msg::NewMessage(response) => self.color = Some(response.color),
+      Msg::CvdMessage(response) => self.simulation = response.simulation,
     }
     true
   }
 
   fn view(&self) -> Html {
+    let color = self
+      .color
+      .as_ref()
+      .map(|color| cvd::seen_as(color, self.simulation).hex_value())
+      .unwrap_or_default();
+
     html! {
-        <div
-          class="colors__current"
-          style=format!("background-color: {};", &self.color)
-        />
+        <div class="colors__current">
+          <div
+            class="colors__current-color"
+            style=format!("background-color: {};", color)
+          />
+        </div>
     }
   }
 }
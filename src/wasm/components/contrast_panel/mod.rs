@@ -0,0 +1,131 @@
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, Response};
+use crate::components::values::color_input::ColorInput;
+use crate::libs::color_transform::Color;
+use crate::libs::contrast::{self, WcagCompliance};
+use crate::texts::TEXTS;
+
+const DEFAULT_CUSTOM_COLOR: &str = "#808080";
+
+pub enum Msg {
+  NewMessage(Response),
+  CustomColorChanged(String),
+}
+
+pub struct ContrastPanel {
+  color: Option<Color>,
+  custom_color: Color,
+  custom_value: String,
+  link: ComponentLink<ContrastPanel>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl ContrastPanel {
+  fn view_badge(&self, title: &str, passed: bool) -> Html {
+    let modifier = if passed { "pass" } else { "fail" };
+
+    html! {
+      <span class={format!("contrast__badge contrast__badge--{}", modifier)}>
+        {title}
+      </span>
+    }
+  }
+
+  fn view_row(&self, color: &Color, other: &Color, label: Html) -> Html {
+    let ratio = contrast::contrast_ratio(color, other);
+    let compliance = WcagCompliance::from_ratio(ratio);
+
+    html! {
+      <div class="contrast__row">
+        <span
+          class="contrast__sample"
+          style={format!("background-color: {}; color: {};", color.hex_value(), other.hex_value())}
+        >
+          {TEXTS.contrast_sample}
+        </span>
+        {label}
+        <span class="contrast__ratio">
+          {contrast::format_ratio(ratio)}
+        </span>
+        {self.view_badge(TEXTS.wcag_aa, compliance.aa_normal)}
+        {self.view_badge(TEXTS.wcag_aaa, compliance.aaa_normal)}
+        {self.view_badge(TEXTS.wcag_aa_large, compliance.aa_large)}
+        {self.view_badge(TEXTS.wcag_aaa_large, compliance.aaa_large)}
+      </div>
+    }
+  }
+}
+
+impl Component for ContrastPanel {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    ContrastPanel {
+      color: None,
+      custom_color: Color::parse(DEFAULT_CUSTOM_COLOR).unwrap(),
+      custom_value: String::from(DEFAULT_CUSTOM_COLOR),
+      link,
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.color = Some(response.color);
+        true
+      }
+
+      Msg::CustomColorChanged(value) => {
+        // the last valid color stays until the input can be parsed again
+        if let Ok(color) = Color::parse(&value) {
+          self.custom_color = color;
+          self.custom_value = value;
+          return true;
+        }
+
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    let color = match &self.color {
+      Some(color) => color,
+      None => return html! {},
+    };
+
+    let white = Color::from_rgb_values(255.0, 255.0, 255.0);
+    let black = Color::from_rgb_values(0.0, 0.0, 0.0);
+
+    html! {
+      <div class="contrast">
+        <span class="contrast__title">
+          {TEXTS.contrast}
+        </span>
+        {self.view_row(color, &white, html! {
+          <span class="contrast__label">{TEXTS.contrast_white}</span>
+        })}
+        {self.view_row(color, &black, html! {
+          <span class="contrast__label">{TEXTS.contrast_black}</span>
+        })}
+        {self.view_row(color, &self.custom_color, html! {
+          <ColorInput
+            class="contrast__label contrast__input"
+            value={self.custom_value.clone()}
+            on_change={self.link.callback(Msg::CustomColorChanged)}
+          />
+        })}
+      </div>
+    }
+  }
+}
@@ -0,0 +1,153 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, Properties, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest, Response};
+use crate::agents::cvd_agent::{CvdAgent, CvdResponse};
+use crate::components::alpha_slider::AlphaSlider;
+use crate::components::color_pallet::ColorPallet;
+use crate::components::color_slider::ColorSlider;
+use crate::libs::color_transform::Color;
+use crate::libs::cvd::Simulation;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Control {
+  Pallet,
+  Hue,
+  Alpha,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+  pub control: Control,
+}
+
+pub enum Msg {
+  CurrentColorMessage(Response),
+  CvdMessage(CvdResponse),
+  ColorChanged(Color),
+  DragStarted,
+  DragEnded,
+}
+
+// connects the pallet or one of the sliders to the current color of the page
+pub struct CurrentColorControl {
+  control: Control,
+  color: Option<Color>,
+  simulation: Option<Simulation>,
+  link: ComponentLink<CurrentColorControl>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+  _cvd_producer: Box<dyn Bridge<CvdAgent>>,
+}
+
+impl CurrentColorControl {
+  fn view_control(&self, color: &Color) -> Html {
+    let on_change = self.link.callback(Msg::ColorChanged);
+    let on_drag_start = self.link.callback(|_| Msg::DragStarted);
+    let on_drag_end = self.link.callback(|_| Msg::DragEnded);
+
+    match self.control {
+      Control::Pallet => html! {
+        <div class="colors__pallet">
+          <ColorPallet
+            value={color.clone()}
+            simulation={self.simulation}
+            on_change={on_change}
+            on_drag_start={on_drag_start}
+            on_drag_end={on_drag_end}
+          />
+        </div>
+      },
+
+      Control::Hue => html! {
+        <ColorSlider
+          value={color.clone()}
+          simulation={self.simulation}
+          on_change={on_change}
+          on_drag_start={on_drag_start}
+          on_drag_end={on_drag_end}
+        />
+      },
+
+      Control::Alpha => html! {
+        <AlphaSlider
+          value={color.clone()}
+          on_change={on_change}
+          on_drag_start={on_drag_start}
+          on_drag_end={on_drag_end}
+        />
+      },
+    }
+  }
+}
+
+impl Component for CurrentColorControl {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let _producer = CurrentColorAgent::bridge(link.callback(Msg::CurrentColorMessage));
+    let _cvd_producer = CvdAgent::bridge(link.callback(Msg::CvdMessage));
+
+    CurrentColorControl {
+      control: props.control,
+      color: None,
+      simulation: None,
+      link,
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+      _cvd_producer,
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.control != props.control {
+      self.control = props.control;
+      return true;
+    }
+
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::CurrentColorMessage(response) => {
+        self.color = Some(response.color);
+        true
+      }
+
+      Msg::CvdMessage(response) => {
+        self.simulation = response.simulation;
+        true
+      }
+
+      Msg::ColorChanged(color) => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::UpdateColor(color));
+        false
+      }
+
+      Msg::DragStarted => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::BeginDrag);
+        false
+      }
+
+      Msg::DragEnded => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::EndDrag);
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    match &self.color {
+      Some(color) => self.view_control(color),
+      None => html! {},
+    }
+  }
+}
@@ -0,0 +1,187 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::html::{ChangeData, InputData};
+use yew::{html, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::cvd_agent::{CvdAgent, CvdRequest};
+use crate::libs::cvd::{CvdModel, Deficiency, Simulation, CVD_MODELS, DEFICIENCIES};
+use crate::texts::TEXTS;
+
+// severity is picked in percents
+const MAX_SEVERITY: u32 = 100;
+
+pub enum Msg {
+  Toggled,
+  DeficiencyChanged(ChangeData),
+  ModelChanged(ChangeData),
+  SeverityChanged(InputData),
+}
+
+pub struct CvdPanel {
+  enabled: bool,
+  deficiency: Deficiency,
+  model: CvdModel,
+  severity: u32,
+  link: ComponentLink<CvdPanel>,
+  cvd_dispatcher: Dispatcher<CvdAgent>,
+}
+
+impl CvdPanel {
+  fn deficiency_title(deficiency: Deficiency) -> &'static str {
+    match deficiency {
+      Deficiency::Protan => TEXTS.cvd_protan,
+      Deficiency::Deutan => TEXTS.cvd_deutan,
+      Deficiency::Tritan => TEXTS.cvd_tritan,
+    }
+  }
+
+  fn model_title(model: CvdModel) -> &'static str {
+    match model {
+      CvdModel::Machado => TEXTS.cvd_machado,
+      CvdModel::Brettel => TEXTS.cvd_brettel,
+    }
+  }
+
+  fn send_simulation(&mut self) {
+    let simulation = if self.enabled {
+      Some(Simulation::new(
+        self.deficiency,
+        f64::from(self.severity) / f64::from(MAX_SEVERITY),
+        self.model,
+      ))
+    } else {
+      None
+    };
+
+    self
+      .cvd_dispatcher
+      .send(CvdRequest::SetSimulation(simulation));
+  }
+
+  fn view_deficiency_option(&self, index: usize) -> Html {
+    let deficiency = DEFICIENCIES[index];
+
+    html! {
+      <option value={index.to_string()} selected={deficiency == self.deficiency}>
+        {CvdPanel::deficiency_title(deficiency)}
+      </option>
+    }
+  }
+
+  fn view_model_option(&self, index: usize) -> Html {
+    let model = CVD_MODELS[index];
+
+    html! {
+      <option value={index.to_string()} selected={model == self.model}>
+        {CvdPanel::model_title(model)}
+      </option>
+    }
+  }
+}
+
+impl Component for CvdPanel {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    CvdPanel {
+      enabled: false,
+      deficiency: Deficiency::Deutan,
+      model: CvdModel::Machado,
+      severity: MAX_SEVERITY,
+      link,
+      cvd_dispatcher: CvdAgent::dispatcher(),
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::Toggled => self.enabled = !self.enabled,
+
+      Msg::DeficiencyChanged(ChangeData::Select(select)) => {
+        match select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| DEFICIENCIES.get(i))
+        {
+          Some(deficiency) => self.deficiency = *deficiency,
+          None => return false,
+        }
+      }
+
+      Msg::ModelChanged(ChangeData::Select(select)) => {
+        match select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| CVD_MODELS.get(i))
+        {
+          Some(model) => self.model = *model,
+          None => return false,
+        }
+      }
+
+      Msg::SeverityChanged(event) => match event.value.parse::<u32>() {
+        Ok(severity) => self.severity = severity.min(MAX_SEVERITY),
+        Err(_) => return false,
+      },
+
+      Msg::DeficiencyChanged(_) | Msg::ModelChanged(_) => return false,
+    }
+
+    self.send_simulation();
+    true
+  }
+
+  fn view(&self) -> Html {
+    html! {
+      <div class="cvd">
+        <span class="cvd__title">
+          {TEXTS.cvd}
+        </span>
+        <label class="cvd__toggle">
+          <input
+            type="checkbox"
+            checked={self.enabled}
+            onchange={self.link.callback(|_| Msg::Toggled)}
+          />
+          {TEXTS.cvd_simulate}
+        </label>
+        <div class="cvd__controls">
+          <select
+            disabled={!self.enabled}
+            onchange={self.link.callback(Msg::DeficiencyChanged)}
+          >
+            {for (0..DEFICIENCIES.len()).map(|index| self.view_deficiency_option(index))}
+          </select>
+          <label class="cvd__control">
+            {TEXTS.cvd_severity}
+            <input
+              class="cvd__severity"
+              type="range"
+              min="0"
+              max={MAX_SEVERITY.to_string()}
+              value={self.severity.to_string()}
+              disabled={!self.enabled}
+              oninput={self.link.callback(Msg::SeverityChanged)}
+            />
+            {format!("{}%", self.severity)}
+          </label>
+          <label class="cvd__control">
+            {TEXTS.cvd_model}
+            <select
+              disabled={!self.enabled}
+              onchange={self.link.callback(Msg::ModelChanged)}
+            >
+              {for (0..CVD_MODELS.len()).map(|index| self.view_model_option(index))}
+            </select>
+          </label>
+        </div>
+      </div>
+    }
+  }
+}
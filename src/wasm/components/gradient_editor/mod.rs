@@ -0,0 +1,401 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, Element, HtmlCanvasElement, HtmlElement, MouseEvent};
+use yew::agent::{Dispatched, Dispatcher};
+use yew::html::{ChangeData, InputData};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, NodeRef, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest, Response};
+use crate::libs::color_transform::interpolation::{HueInterpolation, InterpolationSpace};
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::CssFormat;
+use crate::libs::gradient::{Gradient, GradientShape};
+use crate::services::mouse::{MouseService, MouseTask};
+use crate::texts::TEXTS;
+
+const SPACES: [(&str, InterpolationSpace); 9] = [
+  ("sRGB", InterpolationSpace::Srgb),
+  ("Linear RGB", InterpolationSpace::LinearRgb),
+  ("HSL", InterpolationSpace::Hsl(HueInterpolation::Shorter)),
+  (
+    "HSL longer hue",
+    InterpolationSpace::Hsl(HueInterpolation::Longer),
+  ),
+  ("HSV", InterpolationSpace::Hsv(HueInterpolation::Shorter)),
+  ("Lab", InterpolationSpace::Lab),
+  ("OKLab", InterpolationSpace::Oklab),
+  (
+    "OKLCH",
+    InterpolationSpace::Oklch(HueInterpolation::Shorter),
+  ),
+  (
+    "OKLCH longer hue",
+    InterpolationSpace::Oklch(HueInterpolation::Longer),
+  ),
+];
+
+const SHAPES: [GradientShape; 3] = [
+  GradientShape::Linear,
+  GradientShape::Radial,
+  GradientShape::Conic,
+];
+
+// width of the preview canvas in px, it is stretched to the width of the bar
+const PREVIEW_WIDTH: i32 = 256;
+
+pub enum Msg {
+  CurrentColorMessage(Response),
+  PreviewMouseDown(MouseEvent),
+  StopMouseDown(usize, MouseEvent),
+  MouseMove(MouseEvent),
+  MouseUp,
+  RemoveStop,
+  SpaceChanged(ChangeData),
+  ShapeChanged(ChangeData),
+  AngleChanged(InputData),
+}
+
+struct Tasks {
+  _mouse_move: MouseTask,
+  _mouse_up: MouseTask,
+  _mouse_out: MouseTask,
+}
+
+impl Tasks {
+  fn new(_mouse_move: MouseTask, _mouse_up: MouseTask, _mouse_out: MouseTask) -> Tasks {
+    Tasks {
+      _mouse_move,
+      _mouse_up,
+      _mouse_out,
+    }
+  }
+}
+
+struct DragData {
+  position: f64,
+  start: i32,
+}
+
+pub struct GradientEditor {
+  gradient: Gradient,
+  shape: GradientShape,
+  // the stop which follows the current color of the pallet and the sliders
+  selected: usize,
+  link: ComponentLink<GradientEditor>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+  drag_data: Option<DragData>,
+  // the canvas is only redrawn when the stops or the interpolation space change
+  preview_changed: bool,
+  _tasks: Tasks,
+  preview_ref: NodeRef,
+  canvas_ref: NodeRef,
+}
+
+impl GradientEditor {
+  fn shape_title(shape: GradientShape) -> &'static str {
+    match shape {
+      GradientShape::Linear => TEXTS.gradient_linear,
+      GradientShape::Radial => TEXTS.gradient_radial,
+      GradientShape::Conic => TEXTS.gradient_conic,
+    }
+  }
+
+  fn preview_width(&self) -> i32 {
+    self
+      .preview_ref
+      .cast::<HtmlElement>()
+      .unwrap()
+      .offset_width()
+  }
+
+  fn select_stop(&mut self, index: usize) {
+    self.selected = index;
+
+    let color = self.gradient.get_stops()[index].color.clone();
+    self
+      .current_color_dispatcher
+      .send(CurrentColorRequest::UpdateColor(color));
+  }
+
+  fn handle_preview_mouse_down(&mut self, event: MouseEvent) {
+    let left = self
+      .preview_ref
+      .cast::<Element>()
+      .unwrap()
+      .get_bounding_client_rect()
+      .left() as i32;
+
+    let position = f64::from(event.client_x() - left) / f64::from(self.preview_width());
+    let index = self.gradient.add_stop(position);
+
+    self.select_stop(index);
+    self.drag_data = Some(DragData {
+      position: self.gradient.get_stops()[index].position,
+      start: event.client_x(),
+    });
+  }
+
+  fn handle_stop_mouse_down(&mut self, index: usize, event: MouseEvent) {
+    event.stop_propagation();
+
+    self.select_stop(index);
+    self.drag_data = Some(DragData {
+      position: self.gradient.get_stops()[index].position,
+      start: event.client_x(),
+    });
+  }
+
+  fn handle_mouse_move(&mut self, event: MouseEvent) -> bool {
+    if let Some(drag_data) = &self.drag_data {
+      let diff = f64::from(event.client_x() - drag_data.start) / f64::from(self.preview_width());
+      let position = drag_data.position + diff;
+
+      if let Some(index) = self.gradient.move_stop(self.selected, position) {
+        self.selected = index;
+        self.preview_changed = true;
+        return true;
+      }
+    }
+
+    false
+  }
+
+  fn redraw_canvas(&self) {
+    let canvas = self.canvas_ref.cast::<HtmlCanvasElement>().unwrap();
+
+    canvas.set_width(PREVIEW_WIDTH as u32);
+    canvas.set_height(1);
+
+    let ctx: CanvasRenderingContext2d = canvas
+      .get_context("2d")
+      .unwrap()
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+
+    for i in 0..PREVIEW_WIDTH {
+      let position = f64::from(i) / f64::from(PREVIEW_WIDTH - 1);
+      let color = self.gradient.color_at(position);
+
+      ctx.set_fill_style(&JsValue::from_str(&color.to_css_string(CssFormat::Rgb)));
+      ctx.fill_rect(f64::from(i), 0.0, 1.0, 1.0);
+    }
+  }
+
+  fn view_stop(&self, index: usize) -> Html {
+    let stop = &self.gradient.get_stops()[index];
+    let class = if index == self.selected {
+      "gradient__stop gradient__stop--selected"
+    } else {
+      "gradient__stop"
+    };
+
+    html! {
+      <div
+        class={class}
+        style={format!("left: {}%;", stop.position * 100.0)}
+        onmousedown={self.link.callback(move |event| Msg::StopMouseDown(index, event))}
+      >
+        <div
+          class="gradient__stop-color"
+          style={format!("background-color: {};", stop.color.hex_value())}
+        />
+      </div>
+    }
+  }
+
+  fn view_space_option(&self, index: usize) -> Html {
+    let (title, space) = SPACES[index];
+
+    html! {
+      <option value={index.to_string()} selected={space == self.gradient.get_space()}>
+        {title}
+      </option>
+    }
+  }
+
+  fn view_shape_option(&self, index: usize) -> Html {
+    let shape = SHAPES[index];
+
+    html! {
+      <option value={index.to_string()} selected={shape == self.shape}>
+        {GradientEditor::shape_title(shape)}
+      </option>
+    }
+  }
+}
+
+impl Component for GradientEditor {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::CurrentColorMessage);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    let move_callback = link.callback(Msg::MouseMove);
+    let up_callback = link.callback(|_: MouseEvent| Msg::MouseUp);
+    let out_callback = link.callback(|_: MouseEvent| Msg::MouseUp);
+
+    let _mousemove_task = MouseService::new(String::from("mousemove")).register(move_callback);
+    let _mouseup_task = MouseService::new(String::from("mouseup")).register(up_callback);
+    let _mouseout_task = MouseService::new(String::from("mouseleave")).register(out_callback);
+
+    let _tasks = Tasks::new(_mousemove_task, _mouseup_task, _mouseout_task);
+
+    // the first stop takes the current color as soon as the agent responds
+    let gradient = Gradient::new(
+      Color::from_rgb_values(0.0, 0.0, 0.0),
+      Color::from_rgb_values(255.0, 255.0, 255.0),
+    );
+
+    GradientEditor {
+      gradient,
+      shape: GradientShape::Linear,
+      selected: 0,
+      link,
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+      drag_data: None,
+      preview_changed: true,
+      _tasks,
+      preview_ref: NodeRef::default(),
+      canvas_ref: NodeRef::default(),
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::CurrentColorMessage(response) => {
+        self.gradient.set_stop_color(self.selected, response.color);
+        self.preview_changed = true;
+        true
+      }
+
+      Msg::PreviewMouseDown(event) => {
+        self.handle_preview_mouse_down(event);
+        true
+      }
+
+      Msg::StopMouseDown(index, event) => {
+        self.handle_stop_mouse_down(index, event);
+        true
+      }
+
+      Msg::MouseMove(event) => self.handle_mouse_move(event),
+
+      Msg::MouseUp => {
+        self.drag_data = None;
+        false
+      }
+
+      Msg::RemoveStop => {
+        if self.gradient.remove_stop(self.selected) {
+          self.preview_changed = true;
+          self.select_stop(self.selected.saturating_sub(1));
+          return true;
+        }
+
+        false
+      }
+
+      Msg::SpaceChanged(ChangeData::Select(select)) => {
+        if let Some((_, space)) = select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| SPACES.get(i))
+        {
+          self.gradient.set_space(*space);
+          self.preview_changed = true;
+        }
+        true
+      }
+
+      Msg::ShapeChanged(ChangeData::Select(select)) => {
+        if let Some(shape) = select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| SHAPES.get(i))
+        {
+          self.shape = *shape;
+        }
+        true
+      }
+
+      Msg::AngleChanged(event) => {
+        if let Ok(angle) = event.value.parse::<f64>() {
+          self.gradient.set_angle(angle);
+          return true;
+        }
+
+        false
+      }
+
+      Msg::SpaceChanged(_) | Msg::ShapeChanged(_) => false,
+    }
+  }
+
+  fn rendered(&mut self, _: bool) {
+    if self.preview_changed {
+      self.redraw_canvas();
+      self.preview_changed = false;
+    }
+  }
+
+  fn view(&self) -> Html {
+    let stops = 0..self.gradient.get_stops().len();
+
+    html! {
+      <div class="gradient">
+        <span class="gradient__title">
+          {TEXTS.gradient}
+        </span>
+        <div
+          class="gradient__preview"
+          ref={self.preview_ref.clone()}
+          onmousedown={self.link.callback(Msg::PreviewMouseDown)}
+        >
+          <canvas class="gradient__canvas" ref={self.canvas_ref.clone()} />
+          {for stops.map(|index| self.view_stop(index))}
+        </div>
+        <div class="gradient__controls">
+          <label class="gradient__control">
+            {TEXTS.gradient_space}
+            <select onchange={self.link.callback(Msg::SpaceChanged)}>
+              {for (0..SPACES.len()).map(|index| self.view_space_option(index))}
+            </select>
+          </label>
+          <label class="gradient__control">
+            {TEXTS.gradient_shape}
+            <select onchange={self.link.callback(Msg::ShapeChanged)}>
+              {for (0..SHAPES.len()).map(|index| self.view_shape_option(index))}
+            </select>
+          </label>
+          <label class="gradient__control">
+            {TEXTS.gradient_angle}
+            <input
+              class="gradient__angle"
+              type="number"
+              value={self.gradient.get_angle().to_string()}
+              oninput={self.link.callback(Msg::AngleChanged)}
+            />
+          </label>
+          <button
+            class="gradient__remove"
+            disabled={self.gradient.get_stops().len() <= 2}
+            onclick={self.link.callback(|_| Msg::RemoveStop)}
+          >
+            {TEXTS.gradient_remove_stop}
+          </button>
+        </div>
+        <input class="gradient__css" readonly=true value={self.gradient.to_css(self.shape)} />
+      </div>
+    }
+  }
+}
@@ -0,0 +1,128 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest, Response};
+use crate::libs::color_transform::Color;
+use crate::libs::harmony::{self, Harmony, HarmonySpace, HARMONIES};
+use crate::texts::TEXTS;
+
+pub enum Msg {
+  NewMessage(Response),
+  SpaceToggled,
+  SwatchClick(Color),
+}
+
+pub struct HarmonyPanel {
+  color: Option<Color>,
+  space: HarmonySpace,
+  link: ComponentLink<HarmonyPanel>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl HarmonyPanel {
+  fn title(kind: Harmony) -> &'static str {
+    match kind {
+      Harmony::Complementary => TEXTS.harmony_complementary,
+      Harmony::Analogous => TEXTS.harmony_analogous,
+      Harmony::Triadic => TEXTS.harmony_triadic,
+      Harmony::SplitComplementary => TEXTS.harmony_split_complementary,
+      Harmony::Tetradic => TEXTS.harmony_tetradic,
+    }
+  }
+
+  fn view_swatch(&self, color: Color) -> Html {
+    let hex = color.hex_value();
+
+    html! {
+      <button
+        class="harmony__swatch"
+        title={hex.clone()}
+        onclick={self.link.callback(move |_| Msg::SwatchClick(color.clone()))}
+      >
+        <span class="harmony__swatch-color" style={format!("background-color: {};", hex)} />
+      </button>
+    }
+  }
+
+  fn view_row(&self, color: &Color, kind: Harmony) -> Html {
+    html! {
+      <div class="harmony__row">
+        <span class="harmony__label">
+          {HarmonyPanel::title(kind)}
+        </span>
+        {for harmony::harmony(color, kind, self.space).into_iter().map(|color| self.view_swatch(color))}
+      </div>
+    }
+  }
+}
+
+impl Component for HarmonyPanel {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    HarmonyPanel {
+      color: None,
+      space: HarmonySpace::Hsv,
+      link,
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.color = Some(response.color);
+        true
+      }
+
+      Msg::SpaceToggled => {
+        self.space = match self.space {
+          HarmonySpace::Hsv => HarmonySpace::Oklch,
+          HarmonySpace::Oklch => HarmonySpace::Hsv,
+        };
+        true
+      }
+
+      Msg::SwatchClick(color) => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::UpdateColor(color));
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    let color = match &self.color {
+      Some(color) => color,
+      None => return html! {},
+    };
+
+    html! {
+      <div class="harmony">
+        <span class="harmony__title">
+          {TEXTS.harmony}
+        </span>
+        <label class="harmony__space">
+          <input
+            type="checkbox"
+            checked={self.space == HarmonySpace::Oklch}
+            onchange={self.link.callback(|_| Msg::SpaceToggled)}
+          />
+          {TEXTS.harmony_oklch}
+        </label>
+        {for HARMONIES.iter().map(|kind| self.view_row(color, *kind))}
+      </div>
+    }
+  }
+}
@@ -1,5 +1,15 @@
+mod alpha_slider;
 mod color_pallet;
+pub mod color_picker;
 mod color_slider;
 mod color_value;
+mod contrast_panel;
+mod current_color_control;
+mod cvd_panel;
+mod gradient_editor;
+mod harmony_panel;
+mod palette_match_panel;
+mod palette_panel;
+mod scale_strip;
 mod values;
 pub mod root;
@@ -0,0 +1,147 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::html::ChangeData;
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest, Response};
+use crate::libs::color_transform::Color;
+use crate::libs::palette_db::{BuiltinPalette, PaletteDb, PaletteMatch, BUILTIN_PALETTES};
+use crate::texts::TEXTS;
+
+// how many of the closest entries are shown
+const MATCH_COUNT: usize = 3;
+
+pub enum Msg {
+  NewMessage(Response),
+  PaletteChanged(ChangeData),
+  MatchClick(Color),
+}
+
+pub struct PaletteMatchPanel {
+  color: Option<Color>,
+  palette: BuiltinPalette,
+  db: PaletteDb,
+  link: ComponentLink<PaletteMatchPanel>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl PaletteMatchPanel {
+  fn title(palette: BuiltinPalette) -> &'static str {
+    match palette {
+      BuiltinPalette::Material => TEXTS.palette_match_material,
+      BuiltinPalette::Tailwind => TEXTS.palette_match_tailwind,
+      BuiltinPalette::RalClassic => TEXTS.palette_match_ral,
+    }
+  }
+
+  fn view_option(&self, index: usize) -> Html {
+    let palette = BUILTIN_PALETTES[index];
+
+    html! {
+      <option value={index.to_string()} selected={palette == self.palette}>
+        {PaletteMatchPanel::title(palette)}
+      </option>
+    }
+  }
+
+  fn view_match(&self, palette_match: PaletteMatch) -> Html {
+    let color = palette_match.entry.color.clone();
+    let hex = color.hex_value();
+
+    html! {
+      <div class="matches__row">
+        <button
+          class="matches__swatch"
+          title={hex.clone()}
+          onclick={self.link.callback(move |_| Msg::MatchClick(color.clone()))}
+        >
+          <span class="matches__swatch-color" style={format!("background-color: {};", hex)} />
+        </button>
+        <span class="matches__name">
+          {&palette_match.entry.name}
+        </span>
+        <span class="matches__delta">
+          {format!("ΔE {:.2}", palette_match.delta_e)}
+        </span>
+      </div>
+    }
+  }
+}
+
+impl Component for PaletteMatchPanel {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    let palette = BuiltinPalette::Material;
+
+    PaletteMatchPanel {
+      color: None,
+      palette,
+      db: palette.load(),
+      link,
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.color = Some(response.color);
+        true
+      }
+
+      Msg::PaletteChanged(ChangeData::Select(select)) => {
+        match select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| BUILTIN_PALETTES.get(i))
+        {
+          Some(palette) => {
+            self.palette = *palette;
+            self.db = palette.load();
+            true
+          }
+          None => false,
+        }
+      }
+
+      Msg::PaletteChanged(_) => false,
+
+      Msg::MatchClick(color) => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::UpdateColor(color));
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    let color = match &self.color {
+      Some(color) => color,
+      None => return html! {},
+    };
+
+    html! {
+      <div class="matches">
+        <span class="matches__title">
+          {TEXTS.palette_match}
+        </span>
+        <select class="matches__palette" onchange={self.link.callback(Msg::PaletteChanged)}>
+          {for (0..BUILTIN_PALETTES.len()).map(|index| self.view_option(index))}
+        </select>
+        {for self.db.closest(color, MATCH_COUNT).into_iter().map(|palette_match| self.view_match(palette_match))}
+      </div>
+    }
+  }
+}
@@ -0,0 +1,447 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::html::{ChangeData, InputData};
+use yew::services::reader::{FileData, ReaderService, ReaderTask};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::palette_agent::{PaletteAgent, PaletteRequest, PaletteResponse};
+use crate::libs::palette::{ase, gpl, jasc, paint_net, Swatch, SwatchGroup};
+use crate::services::download::DownloadService;
+use crate::texts::TEXTS;
+
+const FILE_NAME: &str = "palette";
+
+#[derive(Clone, Copy, PartialEq)]
+enum FileFormat {
+  Ase,
+  Gpl,
+  Jasc,
+  PaintNet,
+}
+
+const FILE_FORMATS: [FileFormat; 4] = [
+  FileFormat::Ase,
+  FileFormat::Gpl,
+  FileFormat::Jasc,
+  FileFormat::PaintNet,
+];
+
+impl FileFormat {
+  fn title(self) -> &'static str {
+    match self {
+      FileFormat::Ase => TEXTS.palette_format_ase,
+      FileFormat::Gpl => TEXTS.palette_format_gpl,
+      FileFormat::Jasc => TEXTS.palette_format_jasc,
+      FileFormat::PaintNet => TEXTS.palette_format_paint_net,
+    }
+  }
+
+  fn extension(self) -> &'static str {
+    match self {
+      FileFormat::Ase => "ase",
+      FileFormat::Gpl => "gpl",
+      FileFormat::Jasc => "pal",
+      FileFormat::PaintNet => "txt",
+    }
+  }
+
+  fn mime_type(self) -> &'static str {
+    match self {
+      FileFormat::Ase => "application/octet-stream",
+      _ => "text/plain",
+    }
+  }
+
+  fn from_file_name(name: &str) -> Option<FileFormat> {
+    let (_, extension) = name.rsplit_once('.')?;
+
+    FILE_FORMATS
+      .iter()
+      .copied()
+      .find(|format| extension.eq_ignore_ascii_case(format.extension()))
+  }
+
+  // the text formats have no groups, all swatches go into a single one,
+  // returns the content and the number of swatches the format could not hold
+  fn write(self, groups: &[SwatchGroup]) -> (Vec<u8>, usize) {
+    if self == FileFormat::Ase {
+      return (ase::write(groups), 0);
+    }
+
+    let group = SwatchGroup {
+      name: match groups {
+        [group] => group.name.clone(),
+        _ => TEXTS.palette.to_string(),
+      },
+      swatches: groups
+        .iter()
+        .flat_map(|group| group.swatches.iter().cloned())
+        .collect(),
+    };
+
+    let (source, dropped) = match self {
+      FileFormat::Gpl => (gpl::write(&group), 0),
+      FileFormat::Jasc => (jasc::write(&group), 0),
+      _ => paint_net::write(&group),
+    };
+
+    (source.into_bytes(), dropped)
+  }
+
+  fn read(self, content: &[u8]) -> Result<Vec<SwatchGroup>, String> {
+    let default_group = TEXTS.palette_default_group;
+    let source = String::from_utf8_lossy(content);
+
+    let group = match self {
+      FileFormat::Ase => return ase::read(content, default_group).map_err(|e| e.to_string()),
+      FileFormat::Gpl => gpl::read(&source, default_group),
+      FileFormat::Jasc => jasc::read(&source, default_group),
+      FileFormat::PaintNet => paint_net::read(&source, default_group),
+    };
+
+    group.map(|group| vec![group]).map_err(|e| e.to_string())
+  }
+}
+
+pub enum Msg {
+  NewMessage(PaletteResponse),
+  NameChanged(InputData),
+  AddGroup,
+  RemoveGroup(usize),
+  RenameGroup(usize),
+  AddColor(usize),
+  SelectSwatch(usize, usize),
+  MoveSwatch(isize),
+  RemoveSwatch,
+  ExportFormatChanged(ChangeData),
+  Export,
+  Import(ChangeData),
+  FileLoaded(FileData),
+}
+
+pub struct PalettePanel {
+  groups: Vec<SwatchGroup>,
+  // group and swatch index
+  selected: Option<(usize, usize)>,
+  name: String,
+  export_format: FileFormat,
+  file_error: Option<String>,
+  link: ComponentLink<PalettePanel>,
+  reader_task: Option<ReaderTask>,
+  palette_dispatcher: Dispatcher<PaletteAgent>,
+  _producer: Box<dyn Bridge<PaletteAgent>>,
+}
+
+impl PalettePanel {
+  fn take_name(&mut self) -> String {
+    std::mem::take(&mut self.name)
+  }
+
+  fn view_swatch(&self, group: usize, index: usize, swatch: &Swatch) -> Html {
+    let class = if self.selected == Some((group, index)) {
+      "palette__swatch palette__swatch--selected"
+    } else {
+      "palette__swatch"
+    };
+
+    html! {
+      <button
+        class={class}
+        title={swatch.name.clone()}
+        onclick={self.link.callback(move |_| Msg::SelectSwatch(group, index))}
+      >
+        <span
+          class="palette__swatch-color"
+          style={format!("background-color: {};", swatch.color.hex_value())}
+        />
+      </button>
+    }
+  }
+
+  fn view_group(&self, group: usize, swatch_group: &SwatchGroup) -> Html {
+    html! {
+      <div class="palette__group">
+        <div class="palette__group-header">
+          <span class="palette__group-name">{&swatch_group.name}</span>
+          <button class="palette__button" onclick={self.link.callback(move |_| Msg::AddColor(group))}>
+            {TEXTS.palette_add_color}
+          </button>
+          <button class="palette__button" onclick={self.link.callback(move |_| Msg::RenameGroup(group))}>
+            {TEXTS.palette_rename_group}
+          </button>
+          <button
+            class="palette__button"
+            disabled={self.groups.len() <= 1}
+            onclick={self.link.callback(move |_| Msg::RemoveGroup(group))}
+          >
+            {TEXTS.palette_remove_group}
+          </button>
+        </div>
+        <div class="palette__swatches">
+          {for swatch_group.swatches.iter().enumerate().map(|(index, swatch)| self.view_swatch(group, index, swatch))}
+        </div>
+      </div>
+    }
+  }
+
+  fn view_format_option(&self, index: usize) -> Html {
+    let format = FILE_FORMATS[index];
+
+    html! {
+      <option value={index.to_string()} selected={format == self.export_format}>
+        {format.title()}
+      </option>
+    }
+  }
+
+  fn view_file_error(&self) -> Html {
+    match &self.file_error {
+      Some(error) => html! {
+        <div class="palette__error">{error}</div>
+      },
+      None => html! {},
+    }
+  }
+
+  fn view_selected(&self) -> Html {
+    let swatch = self
+      .selected
+      .and_then(|(group, index)| self.groups.get(group)?.swatches.get(index));
+
+    match swatch {
+      Some(swatch) => html! {
+        <div class="palette__selected">
+          <span class="palette__selected-name">{&swatch.name}</span>
+          <button class="palette__button" onclick={self.link.callback(|_| Msg::MoveSwatch(-1))}>
+            {TEXTS.palette_move_left}
+          </button>
+          <button class="palette__button" onclick={self.link.callback(|_| Msg::MoveSwatch(1))}>
+            {TEXTS.palette_move_right}
+          </button>
+          <button class="palette__button" onclick={self.link.callback(|_| Msg::RemoveSwatch)}>
+            {TEXTS.palette_remove_color}
+          </button>
+        </div>
+      },
+      None => html! {},
+    }
+  }
+}
+
+impl Component for PalettePanel {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+    let _producer = PaletteAgent::bridge(callback);
+
+    PalettePanel {
+      groups: Vec::new(),
+      selected: None,
+      name: String::new(),
+      export_format: FileFormat::Ase,
+      file_error: None,
+      link,
+      reader_task: None,
+      palette_dispatcher: PaletteAgent::dispatcher(),
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.groups = response.groups;
+        true
+      }
+
+      Msg::NameChanged(event) => {
+        self.name = event.value;
+        false
+      }
+
+      Msg::AddGroup => {
+        let name = self.take_name();
+        let name = if name.trim().is_empty() {
+          format!("{} {}", TEXTS.palette_group, self.groups.len() + 1)
+        } else {
+          name
+        };
+
+        self.palette_dispatcher.send(PaletteRequest::AddGroup(name));
+        true
+      }
+
+      Msg::RemoveGroup(group) => {
+        self.selected = None;
+        self
+          .palette_dispatcher
+          .send(PaletteRequest::RemoveGroup(group));
+        false
+      }
+
+      // the name input holds the new name, nothing happens while it is empty
+      Msg::RenameGroup(group) => {
+        let name = self.take_name();
+        if name.trim().is_empty() {
+          return false;
+        }
+
+        self
+          .palette_dispatcher
+          .send(PaletteRequest::RenameGroup(group, name.trim().to_string()));
+        true
+      }
+
+      Msg::AddColor(group) => {
+        let name = self.take_name();
+        self
+          .palette_dispatcher
+          .send(PaletteRequest::AddCurrentColor(group, name));
+        true
+      }
+
+      Msg::SelectSwatch(group, index) => {
+        self.selected = Some((group, index));
+        self
+          .palette_dispatcher
+          .send(PaletteRequest::SelectSwatch(group, index));
+        true
+      }
+
+      Msg::MoveSwatch(offset) => {
+        if let Some((group, index)) = self.selected {
+          let to = (index as isize + offset).max(0) as usize;
+          let last = self.groups.get(group).map_or(0, |swatch_group| {
+            swatch_group.swatches.len().saturating_sub(1)
+          });
+
+          self.selected = Some((group, to.min(last)));
+          self
+            .palette_dispatcher
+            .send(PaletteRequest::MoveSwatch(group, index, to));
+        }
+        false
+      }
+
+      Msg::RemoveSwatch => {
+        if let Some((group, index)) = self.selected.take() {
+          self
+            .palette_dispatcher
+            .send(PaletteRequest::RemoveSwatch(group, index));
+        }
+        false
+      }
+
+      Msg::ExportFormatChanged(ChangeData::Select(select)) => {
+        if let Some(format) = select
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|i| FILE_FORMATS.get(i))
+        {
+          self.export_format = *format;
+        }
+        false
+      }
+
+      Msg::ExportFormatChanged(_) => false,
+
+      Msg::Export => {
+        let format = self.export_format;
+        let file_name = format!("{}.{}", FILE_NAME, format.extension());
+
+        let (content, dropped) = format.write(&self.groups);
+        let _ = DownloadService::new().download(&file_name, &content, format.mime_type());
+
+        self.file_error = if dropped > 0 {
+          Some(format!("{}: {}", TEXTS.palette_colors_left_out, dropped))
+        } else {
+          None
+        };
+        true
+      }
+
+      Msg::Import(ChangeData::Files(files)) => {
+        let file = match files.get(0) {
+          Some(file) => file,
+          None => return false,
+        };
+
+        let callback = self.link.callback(Msg::FileLoaded);
+        match ReaderService::read_file(file, callback) {
+          Ok(task) => {
+            self.file_error = None;
+            self.reader_task = Some(task);
+          }
+          Err(_) => self.file_error = Some(TEXTS.palette_import_error.to_string()),
+        }
+        true
+      }
+
+      Msg::Import(_) => false,
+
+      Msg::FileLoaded(data) => {
+        self.reader_task = None;
+
+        let groups = match FileFormat::from_file_name(&data.name) {
+          Some(format) => format.read(&data.content),
+          None => Err(TEXTS.palette_unknown_format.to_string()),
+        };
+
+        match groups {
+          Ok(groups) => self
+            .palette_dispatcher
+            .send(PaletteRequest::ImportGroups(groups)),
+          Err(error) => self.file_error = Some(format!("{}: {}", TEXTS.palette_import_error, error)),
+        }
+        true
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+      <div class="palette">
+        <span class="palette__title">
+          {TEXTS.palette}
+        </span>
+        <div class="palette__controls">
+          <input
+            class="palette__name"
+            placeholder={TEXTS.palette_name}
+            value={self.name.clone()}
+            oninput={self.link.callback(Msg::NameChanged)}
+          />
+          <button class="palette__button" onclick={self.link.callback(|_| Msg::AddGroup)}>
+            {TEXTS.palette_add_group}
+          </button>
+        </div>
+        <div class="palette__controls">
+          <select class="palette__format" onchange={self.link.callback(Msg::ExportFormatChanged)}>
+            {for (0..FILE_FORMATS.len()).map(|index| self.view_format_option(index))}
+          </select>
+          <button class="palette__button" onclick={self.link.callback(|_| Msg::Export)}>
+            {TEXTS.palette_export}
+          </button>
+          <label class="palette__button palette__import">
+            {TEXTS.palette_import}
+            <input
+              class="palette__file"
+              type="file"
+              accept=".ase,.gpl,.pal,.txt"
+              onchange={self.link.callback(Msg::Import)}
+            />
+          </label>
+        </div>
+        {self.view_file_error()}
+        {for self.groups.iter().enumerate().map(|(group, swatch_group)| self.view_group(group, swatch_group))}
+        {self.view_selected()}
+      </div>
+    }
+  }
+}
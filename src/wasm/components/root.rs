@@ -1,8 +1,14 @@
 use yew::{html, Component, ComponentLink, Html, ShouldRender};
 
-use crate::components::color_pallet::ColorPallet;
-use crate::components::color_slider::ColorSlider;
 use crate::components::color_value::ColorValue;
+use crate::components::contrast_panel::ContrastPanel;
+use crate::components::current_color_control::{Control, CurrentColorControl};
+use crate::components::cvd_panel::CvdPanel;
+use crate::components::gradient_editor::GradientEditor;
+use crate::components::harmony_panel::HarmonyPanel;
+use crate::components::palette_match_panel::PaletteMatchPanel;
+use crate::components::palette_panel::PalettePanel;
+use crate::components::scale_strip::ScaleStrip;
 
 use crate::components::values;
 
@@ -35,10 +41,19 @@ impl Component for Root {
 
             <div class="colors">
               <ColorValue />
-              <ColorPallet />
+              <CurrentColorControl control={Control::Pallet} />
             </div>
 
-            <ColorSlider />
+            <CvdPanel />
+            <ContrastPanel />
+            <HarmonyPanel />
+            <GradientEditor />
+            <PalettePanel />
+            <PaletteMatchPanel />
+
+            <CurrentColorControl control={Control::Hue} />
+            <ScaleStrip />
+            <CurrentColorControl control={Control::Alpha} />
 
             {values::view()}
         </div>
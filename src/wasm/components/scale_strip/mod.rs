@@ -0,0 +1,129 @@
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::agents::current_color_agent::{CurrentColorAgent, CurrentColorRequest, Response};
+use crate::libs::color_scale::{self, ScaleKind, SCALE_KINDS};
+use crate::libs::color_transform::Color;
+use crate::texts::TEXTS;
+
+// 50, 100, 200 ... 900 like the Tailwind palettes
+const SCALE_STEPS: usize = 10;
+
+pub enum Msg {
+  NewMessage(Response),
+  KindChanged(ScaleKind),
+  SwatchClick(Color),
+}
+
+pub struct ScaleStrip {
+  colors: Vec<Color>,
+  color: Option<Color>,
+  kind: ScaleKind,
+  link: ComponentLink<ScaleStrip>,
+  current_color_dispatcher: Dispatcher<CurrentColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl ScaleStrip {
+  fn title(kind: ScaleKind) -> &'static str {
+    match kind {
+      ScaleKind::Tint => TEXTS.scale_tint,
+      ScaleKind::Shade => TEXTS.scale_shade,
+      ScaleKind::Tone => TEXTS.scale_tone,
+      ScaleKind::Lightness => TEXTS.scale_lightness,
+    }
+  }
+
+  fn update_colors(&mut self) {
+    if let Some(color) = &self.color {
+      self.colors = color_scale::scale(color, self.kind, SCALE_STEPS);
+    }
+  }
+
+  fn view_kind(&self, kind: ScaleKind) -> Html {
+    let class = if kind == self.kind {
+      "scale__kind scale__kind--active"
+    } else {
+      "scale__kind"
+    };
+
+    html! {
+      <button class={class} onclick={self.link.callback(move |_| Msg::KindChanged(kind))}>
+        {ScaleStrip::title(kind)}
+      </button>
+    }
+  }
+
+  fn view_swatch(&self, color: &Color) -> Html {
+    let hex = color.hex_value();
+    let color = color.clone();
+
+    html! {
+      <button
+        class="scale__swatch"
+        title={hex.clone()}
+        style={format!("background-color: {};", hex)}
+        onclick={self.link.callback(move |_| Msg::SwatchClick(color.clone()))}
+      />
+    }
+  }
+}
+
+impl Component for ScaleStrip {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    ScaleStrip {
+      colors: Vec::new(),
+      color: None,
+      kind: ScaleKind::Lightness,
+      link,
+      current_color_dispatcher: CurrentColorAgent::dispatcher(),
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.color = Some(response.color);
+        self.update_colors();
+        true
+      }
+
+      Msg::KindChanged(kind) => {
+        self.kind = kind;
+        self.update_colors();
+        true
+      }
+
+      Msg::SwatchClick(color) => {
+        self
+          .current_color_dispatcher
+          .send(CurrentColorRequest::UpdateColor(color));
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+      <div class="scale">
+        <div class="scale__kinds">
+          {for SCALE_KINDS.iter().map(|kind| self.view_kind(*kind))}
+        </div>
+        <div class="scale__swatches">
+          {for self.colors.iter().map(|color| self.view_swatch(color))}
+        </div>
+      </div>
+    }
+  }
+}
@@ -51,7 +51,7 @@ impl Component for CmykValue {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::NewMessage(response) => {
-        self.cmyk_value = response.cmyk;
+        self.cmyk_value = response.color.cmyk_value();
         true
       }
       Msg::ValueChanged(value) => {
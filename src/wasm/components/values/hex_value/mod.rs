@@ -4,6 +4,7 @@ use yew::agent::{Dispatched, Dispatcher};
 use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
 
 use crate::components::values::color_input::ColorInput;
+use crate::libs::named_color::{self, NearestName};
 use crate::texts::TEXTS;
 
 pub enum Msg {
@@ -14,6 +15,7 @@ pub enum Msg {
 pub struct HexValue {
   hex_value: String,
   last_hex_value: String,
+  nearest_name: Option<NearestName>,
   link: ComponentLink<HexValue>,
   hex_color_agent: Dispatcher<HexColorAgent>,
   _producer: Box<dyn Bridge<CurrentColorAgent>>,
@@ -23,6 +25,17 @@ impl HexValue {
   fn handle_value_change(&mut self, value: String) {
     self.hex_color_agent.send(Request::HexColorChangeMsg(value));
   }
+
+  fn view_nearest_name(&self) -> Html {
+    match &self.nearest_name {
+      Some(nearest) => html! {
+        <span class="hex-color__name">
+          {format!("{}: {} (ΔE {:.2})", TEXTS.nearest_name, nearest.name, nearest.delta_e)}
+        </span>
+      },
+      None => html! {},
+    }
+  }
 }
 
 impl Component for HexValue {
@@ -38,6 +51,7 @@ impl Component for HexValue {
     HexValue {
       hex_value: String::from(""),
       last_hex_value: String::from(""),
+      nearest_name: None,
       link,
       hex_color_agent,
       _producer,
@@ -51,8 +65,10 @@ impl Component for HexValue {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::NewMessage(response) => {
-        self.hex_value = response.hex.to_string();
-        self.last_hex_value = response.hex;
+        self.nearest_name = Some(named_color::nearest(&response.color));
+        let hex = response.color.hex_value();
+        self.hex_value = hex.clone();
+        self.last_hex_value = hex;
         true
       }
 
@@ -74,6 +90,7 @@ impl Component for HexValue {
             value={self.hex_value.clone()}
             on_change={self.link.callback(|value: String| Msg::ValueChanged(value))}
           />
+          {self.view_nearest_name()}
         </div>
     }
   }
@@ -49,7 +49,7 @@ impl Component for HslValue {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::NewMessage(response) => {
-        self.hsl_value = response.hsl;
+        self.hsl_value = response.color.hsl_value();
         true
       }
       Msg::ValueChanged(e) => {
@@ -49,7 +49,7 @@ impl Component for HsvValue {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::NewMessage(response) => {
-        self.hsv_value = response.hsv;
+        self.hsv_value = response.color.hsv_value();
         true
       }
       Msg::ValueChanged(value) => {
@@ -0,0 +1,76 @@
+use crate::agents::current_color_agent::{CurrentColorAgent, Response};
+use crate::agents::lab_color_agent::{LabColorAgent, Request};
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::components::values::color_input::ColorInput;
+use crate::texts::TEXTS;
+
+pub enum Msg {
+  NewMessage(Response),
+  ValueChanged(String),
+}
+
+pub struct LabValue {
+  lab_value: String,
+  link: ComponentLink<LabValue>,
+  lab_color_agent: Dispatcher<LabColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl LabValue {
+  fn handle_value_change(&mut self, value: String) {
+    self.lab_color_agent.send(Request::LabColorChangeMsg(value));
+  }
+}
+
+impl Component for LabValue {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+
+    let lab_color_agent = LabColorAgent::dispatcher();
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    LabValue {
+      lab_value: String::from(""),
+      link,
+      lab_color_agent,
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.lab_value = response.color.lab_value();
+        true
+      }
+      Msg::ValueChanged(e) => {
+        self.handle_value_change(e);
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+        <div class="value-color">
+          <span class="value-color__title">
+            {TEXTS.lab}
+          </span>
+          <ColorInput
+            class="value-color__input"
+            value={self.lab_value.clone()}
+            on_change={self.link.callback(|value: String| Msg::ValueChanged(value))}
+          />
+        </div>
+    }
+  }
+}
@@ -0,0 +1,76 @@
+use crate::agents::current_color_agent::{CurrentColorAgent, Response};
+use crate::agents::lch_color_agent::{LchColorAgent, Request};
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::components::values::color_input::ColorInput;
+use crate::texts::TEXTS;
+
+pub enum Msg {
+  NewMessage(Response),
+  ValueChanged(String),
+}
+
+pub struct LchValue {
+  lch_value: String,
+  link: ComponentLink<LchValue>,
+  lch_color_agent: Dispatcher<LchColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl LchValue {
+  fn handle_value_change(&mut self, value: String) {
+    self.lch_color_agent.send(Request::LchColorChangeMsg(value));
+  }
+}
+
+impl Component for LchValue {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+
+    let lch_color_agent = LchColorAgent::dispatcher();
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    LchValue {
+      lch_value: String::from(""),
+      link,
+      lch_color_agent,
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.lch_value = response.color.lch_value();
+        true
+      }
+      Msg::ValueChanged(e) => {
+        self.handle_value_change(e);
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+        <div class="value-color">
+          <span class="value-color__title">
+            {TEXTS.lch}
+          </span>
+          <ColorInput
+            class="value-color__input"
+            value={self.lch_value.clone()}
+            on_change={self.link.callback(|value: String| Msg::ValueChanged(value))}
+          />
+        </div>
+    }
+  }
+}
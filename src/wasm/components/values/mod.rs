@@ -1,8 +1,11 @@
 mod cmyk_value;
-mod color_input;
+pub mod color_input;
 mod hex_value;
 mod hsl_value;
 mod hsv_value;
+mod lab_value;
+mod lch_value;
+mod oklch_value;
 mod rgb_value;
 
 use yew::{html, Html};
@@ -11,6 +14,9 @@ use cmyk_value::CmykValue;
 use hex_value::HexValue;
 use hsl_value::HslValue;
 use hsv_value::HsvValue;
+use lab_value::LabValue;
+use lch_value::LchValue;
+use oklch_value::OklchValue;
 use rgb_value::RgbValue;
 
 pub fn view() -> Html {
@@ -23,6 +29,9 @@ pub fn view() -> Html {
         <CmykValue />
         <HsvValue />
         <HslValue />
+        <LabValue />
+        <LchValue />
+        <OklchValue />
       </div>
     </div>
   }
@@ -0,0 +1,78 @@
+use crate::agents::current_color_agent::{CurrentColorAgent, Response};
+use crate::agents::oklch_color_agent::{OklchColorAgent, Request};
+use yew::agent::{Dispatched, Dispatcher};
+use yew::{html, Bridge, Bridged, Component, ComponentLink, Html, ShouldRender};
+
+use crate::components::values::color_input::ColorInput;
+use crate::texts::TEXTS;
+
+pub enum Msg {
+  NewMessage(Response),
+  ValueChanged(String),
+}
+
+pub struct OklchValue {
+  oklch_value: String,
+  link: ComponentLink<OklchValue>,
+  oklch_color_agent: Dispatcher<OklchColorAgent>,
+  _producer: Box<dyn Bridge<CurrentColorAgent>>,
+}
+
+impl OklchValue {
+  fn handle_value_change(&mut self, value: String) {
+    self
+      .oklch_color_agent
+      .send(Request::OklchColorChangeMsg(value));
+  }
+}
+
+impl Component for OklchValue {
+  type Message = Msg;
+  type Properties = ();
+
+  fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let callback = link.callback(Msg::NewMessage);
+
+    let oklch_color_agent = OklchColorAgent::dispatcher();
+    let _producer = CurrentColorAgent::bridge(callback);
+
+    OklchValue {
+      oklch_value: String::from(""),
+      link,
+      oklch_color_agent,
+      _producer,
+    }
+  }
+
+  fn change(&mut self, _: Self::Properties) -> bool {
+    false
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::NewMessage(response) => {
+        self.oklch_value = response.color.oklch_value();
+        true
+      }
+      Msg::ValueChanged(e) => {
+        self.handle_value_change(e);
+        false
+      }
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+        <div class="value-color">
+          <span class="value-color__title">
+            {TEXTS.oklch}
+          </span>
+          <ColorInput
+            class="value-color__input"
+            value={self.oklch_value.clone()}
+            on_change={self.link.callback(|value: String| Msg::ValueChanged(value))}
+          />
+        </div>
+    }
+  }
+}
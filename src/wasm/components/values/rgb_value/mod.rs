@@ -50,7 +50,7 @@ impl Component for RgbValue {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::NewMessage(response) => {
-        self.rgb_value = response.rgb;
+        self.rgb_value = response.color.rgb_value();
         true
       }
       Msg::ValueChanged(v) => {
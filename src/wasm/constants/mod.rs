@@ -10,14 +10,57 @@ pub const MAX_RGB: f32 = 255.0;
 pub const MIN_CMYK: f32 = 0.0;
 pub const MAX_CMYK: f32 = 100.0;
 
+pub const MIN_LAB_L: f32 = 0.0;
+pub const MAX_LAB_L: f32 = 100.0;
+pub const MIN_LAB_AB: f32 = -128.0;
+pub const MAX_LAB_AB: f32 = 128.0;
+pub const MIN_LCH_C: f32 = 0.0;
+pub const MAX_LCH_C: f32 = 150.0;
+
+pub const MIN_OKLAB_L: f32 = 0.0;
+pub const MAX_OKLAB_L: f32 = 100.0;
+pub const MIN_OKLAB_AB: f32 = -0.4;
+pub const MAX_OKLAB_AB: f32 = 0.4;
+pub const MIN_OKLCH_C: f32 = 0.0;
+pub const MAX_OKLCH_C: f32 = 0.4;
+
+pub const MIN_ALPHA: f32 = 0.0;
+pub const MAX_ALPHA: f32 = 1.0;
+
 lazy_static! {
   pub static ref CMYK_REG_EXP: Regex =
-    Regex::new(r"^(\d{1,3})%,\s*(\d{1,3})%,\s*(\d{1,3})%,\s*(\d{1,3})%$").unwrap();
+    Regex::new(r"^(\d{1,3})%,\s*(\d{1,3})%,\s*(\d{1,3})%,\s*(\d{1,3})%(?:,\s*(\d+(?:\.\d+)?))?$")
+      .unwrap();
   pub static ref HEX_SHORT_REG_EXP: Regex =
-    Regex::new(r"^#?(?P<r>[\dA-Fa-f])(?P<g>[\dA-Fa-f])(?P<b>[\dA-Fa-f])$").unwrap();
+    Regex::new(r"^#?(?P<r>[\dA-Fa-f])(?P<g>[\dA-Fa-f])(?P<b>[\dA-Fa-f])(?P<a>[\dA-Fa-f])?$")
+      .unwrap();
   pub static ref HEX_LONG_REG_EXP: Regex =
-    Regex::new(r"^#?([\dA-Fa-f]{2})([\dA-Fa-f]{2})([\dA-Fa-f]{2})$").unwrap();
+    Regex::new(r"^#?([\dA-Fa-f]{2})([\dA-Fa-f]{2})([\dA-Fa-f]{2})([\dA-Fa-f]{2})?$").unwrap();
+  pub static ref HSL_REG_EXP: Regex =
+    Regex::new(r"^(\d{1,3})°,\s*(\d{1,3})%,\s*(\d{1,3})%(?:,\s*(\d+(?:\.\d+)?))?$").unwrap();
   pub static ref HSV_REG_EXP: Regex =
-    Regex::new(r"(\d{1,3})°,\s*(\d{1,3})%,\s*(\d{1,3})%").unwrap();
-  pub static ref RGB_REG_EXP: Regex = Regex::new(r"^(\d{1,3}),\s*(\d{1,3}),\s*(\d{1,3})$").unwrap();
+    Regex::new(r"(\d{1,3})°,\s*(\d{1,3})%,\s*(\d{1,3})%(?:,\s*(\d+(?:\.\d+)?))?").unwrap();
+  pub static ref LAB_REG_EXP: Regex = Regex::new(
+    r"^(\d{1,3}(?:\.\d+)?),\s*(-?\d{1,3}(?:\.\d+)?),\s*(-?\d{1,3}(?:\.\d+)?)(?:,\s*(\d+(?:\.\d+)?))?$"
+  )
+  .unwrap();
+  pub static ref LCH_REG_EXP: Regex = Regex::new(
+    r"^(\d{1,3}(?:\.\d+)?),\s*(\d{1,3}(?:\.\d+)?),\s*(\d{1,3}(?:\.\d+)?)°(?:,\s*(\d+(?:\.\d+)?))?$"
+  )
+  .unwrap();
+  pub static ref OKLAB_REG_EXP: Regex = Regex::new(
+    r"^(\d{1,3}(?:\.\d+)?)%\s+(-?\d(?:\.\d+)?)\s+(-?\d(?:\.\d+)?)(?:\s*/\s*(\d+(?:\.\d+)?))?$"
+  )
+  .unwrap();
+  pub static ref OKLCH_REG_EXP: Regex = Regex::new(
+    r"^(\d{1,3}(?:\.\d+)?)%\s+(\d(?:\.\d+)?)\s+(\d{1,3}(?:\.\d+)?)(?:\s*/\s*(\d+(?:\.\d+)?))?$"
+  )
+  .unwrap();
+  pub static ref RGB_REG_EXP: Regex =
+    Regex::new(r"^(\d{1,3}),\s*(\d{1,3}),\s*(\d{1,3})(?:,\s*(\d+(?:\.\d+)?))?$").unwrap();
+  pub static ref CSS_NUMBER_REG_EXP: Regex =
+    Regex::new(r"^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$").unwrap();
+  pub static ref NUMBER_REG_EXP: Regex = Regex::new(r"^\d+(\.\d+)?$").unwrap();
+  pub static ref SIGNED_NUMBER_REG_EXP: Regex = Regex::new(r"^-?\d+(\.\d+)?$").unwrap();
+  pub static ref NOTATION_REG_EXP: Regex = Regex::new(r"^([A-Za-z-]+)\((.*)\)$").unwrap();
 }
@@ -10,6 +10,8 @@ use yew::prelude::*;
 
 use components::root::Root;
 
+pub use components::color_picker::{ColorModel, ColorPicker, Props as ColorPickerProps};
+
 #[macro_use]
 extern crate lazy_static;
 
@@ -0,0 +1,163 @@
+use crate::libs::color_transform::Color;
+
+// APCA 0.0.98G-4g constants
+const MAIN_TRC: f64 = 2.4;
+const R_COEFFICIENT: f64 = 0.2126729;
+const G_COEFFICIENT: f64 = 0.7151522;
+const B_COEFFICIENT: f64 = 0.0721750;
+
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+const REV_TXT: f64 = 0.62;
+const REV_BG: f64 = 0.65;
+
+const BLACK_THRESHOLD: f64 = 0.022;
+const BLACK_CLAMP: f64 = 1.414;
+const SCALE_BLACK_ON_WHITE: f64 = 1.14;
+const SCALE_WHITE_ON_BLACK: f64 = 1.14;
+const LOW_BLACK_ON_WHITE_OFFSET: f64 = 0.027;
+const LOW_WHITE_ON_BLACK_OFFSET: f64 = 0.027;
+const DELTA_Y_MIN: f64 = 0.0005;
+const LOW_CLIP: f64 = 0.1;
+
+/// Font weights the font sizes of [`minimum_font_sizes`] are given for.
+pub const FONT_WEIGHTS: [u32; 9] = [100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+// sentinels of the lookup table
+const PROHIBITED: f64 = 999.0;
+const NON_TEXT: f64 = 777.0;
+
+const LC_STEP: f64 = 5.0;
+
+// APCA font lookup table 0.1.7 G-4g from the apca-w3 reference implementation, one row per 5 Lc
+// and one column per weight, the Lc 5 row repeats Lc 0 which the table skips
+const FONT_LOOKUP: [[f64; 9]; 26] = [
+  [PROHIBITED; 9], // Lc 0
+  [PROHIBITED; 9], // Lc 5
+  [PROHIBITED; 9], // Lc 10
+  [NON_TEXT; 9],   // Lc 15
+  [NON_TEXT; 9],   // Lc 20
+  [
+    NON_TEXT, NON_TEXT, NON_TEXT, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0,
+  ], // Lc 25
+  [
+    NON_TEXT, NON_TEXT, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0,
+  ], // Lc 30
+  [NON_TEXT, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0], // Lc 35
+  [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0], // Lc 40
+  [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0], // Lc 45
+  [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0], // Lc 50
+  [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0], // Lc 55
+  [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0], // Lc 60
+  [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0], // Lc 65
+  [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0], // Lc 70
+  [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0], // Lc 75
+  [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0], // Lc 80
+  [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0], // Lc 85
+  [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0], // Lc 90
+  [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0], // Lc 95
+  [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0], // Lc 100
+  [39.0, 25.0, 18.0, 14.5, 14.0, 13.0, 12.0, 16.0, 18.0], // Lc 105
+  [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0], // Lc 110
+  [34.0, 22.5, 17.5, 13.5, 12.5, 11.5, 10.0, 16.0, 18.0], // Lc 115
+  [32.0, 21.0, 17.0, 13.0, 12.0, 11.0, 10.0, 16.0, 18.0], // Lc 120
+  [30.0, 20.0, 16.5, 12.5, 11.5, 10.5, 10.0, 16.0, 18.0], // Lc 125
+];
+
+/// What a font weight can be used for at a given contrast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontUse {
+  /// The contrast is too low for any content.
+  Prohibited,
+  /// Enough for non-text elements like dividers or large icons, but not for text.
+  NonText,
+  /// Text is readable from this size on, in CSS px.
+  MinimumSize(f64),
+}
+
+impl FontUse {
+  fn from_size(size: f64) -> FontUse {
+    if size >= PROHIBITED {
+      FontUse::Prohibited
+    } else if size >= NON_TEXT {
+      FontUse::NonText
+    } else {
+      FontUse::MinimumSize(size)
+    }
+  }
+}
+
+// screen luminance estimate, APCA uses a simple 2.4 exponent instead of the piecewise sRGB curve
+fn screen_luminance(color: &Color) -> f64 {
+  let [red, green, blue] = color.srgb_values();
+
+  R_COEFFICIENT * red.powf(MAIN_TRC)
+    + G_COEFFICIENT * green.powf(MAIN_TRC)
+    + B_COEFFICIENT * blue.powf(MAIN_TRC)
+}
+
+// soft clamp of near black luminance
+fn clamp_black(luminance: f64) -> f64 {
+  if luminance < BLACK_THRESHOLD {
+    luminance + (BLACK_THRESHOLD - luminance).powf(BLACK_CLAMP)
+  } else {
+    luminance
+  }
+}
+
+// positive for dark text on a light background, negative for light text on a dark one, about ±108 at most
+pub fn lightness_contrast(text: &Color, background: &Color) -> f64 {
+  let text_y = clamp_black(screen_luminance(text));
+  let background_y = clamp_black(screen_luminance(background));
+
+  if (background_y - text_y).abs() < DELTA_Y_MIN {
+    return 0.0;
+  }
+
+  let contrast = if background_y > text_y {
+    let sapc = (background_y.powf(NORM_BG) - text_y.powf(NORM_TXT)) * SCALE_BLACK_ON_WHITE;
+
+    if sapc < LOW_CLIP {
+      0.0
+    } else {
+      sapc - LOW_BLACK_ON_WHITE_OFFSET
+    }
+  } else {
+    let sapc = (background_y.powf(REV_BG) - text_y.powf(REV_TXT)) * SCALE_WHITE_ON_BLACK;
+
+    if sapc > -LOW_CLIP {
+      0.0
+    } else {
+      sapc + LOW_WHITE_ON_BLACK_OFFSET
+    }
+  };
+
+  contrast * 100.0
+}
+
+// font use for each of FONT_WEIGHTS, the polarity doesn't matter and sizes between two rows are
+// interpolated like the reference implementation does
+pub fn minimum_font_sizes(lc: f64) -> [FontUse; 9] {
+  let last = FONT_LOOKUP.len() - 1;
+  let position = (lc.abs() / LC_STEP).min(last as f64);
+  let index = position.floor() as usize;
+
+  let lower = &FONT_LOOKUP[index];
+  let upper = &FONT_LOOKUP[(index + 1).min(last)];
+  let t = position - index as f64;
+
+  [0, 1, 2, 3, 4, 5, 6, 7, 8].map(|weight| {
+    // a weight which is not usable for text stays so up to the next row
+    if lower[weight] >= NON_TEXT {
+      FontUse::from_size(lower[weight])
+    } else {
+      FontUse::from_size(lower[weight] + (upper[weight] - lower[weight]) * t)
+    }
+  })
+}
+
+// font use for a weight between 100 and 900, None for weights between the steps of 100
+pub fn minimum_font_size(lc: f64, weight: u32) -> Option<FontUse> {
+  let index = FONT_WEIGHTS.iter().position(|w| *w == weight)?;
+  Some(minimum_font_sizes(lc)[index])
+}
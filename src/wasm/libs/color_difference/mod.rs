@@ -0,0 +1,127 @@
+use crate::libs::color_transform::lab_color::Lab;
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::Color;
+
+// 25^7, used by the chroma compensation of CIEDE2000
+const CHROMA_POWER: f64 = 6_103_515_625.0;
+
+/// Color difference formulas, from the simplest to the most perceptually uniform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaE {
+  Cie76,
+  Cie94,
+  Ciede2000,
+  Ok,
+}
+
+// the first color is the reference one, CIE94 is not symmetric
+pub fn delta_e(first: &Color, second: &Color, metric: DeltaE) -> f64 {
+  match metric {
+    DeltaE::Cie76 => cie76(&first.to_lab(), &second.to_lab()),
+    DeltaE::Cie94 => cie94(&first.to_lab(), &second.to_lab()),
+    DeltaE::Ciede2000 => ciede2000(&first.to_lab(), &second.to_lab()),
+    DeltaE::Ok => delta_e_ok(&first.to_oklab(), &second.to_oklab()),
+  }
+}
+
+pub fn cie76(first: &Lab, second: &Lab) -> f64 {
+  let delta_l = first.get_lightness() - second.get_lightness();
+  let delta_a = first.get_a() - second.get_a();
+  let delta_b = first.get_b() - second.get_b();
+
+  (delta_l.powi(2) + delta_a.powi(2) + delta_b.powi(2)).sqrt()
+}
+
+// graphic arts weights: kL = 1, K1 = 0.045, K2 = 0.015
+pub fn cie94(first: &Lab, second: &Lab) -> f64 {
+  let delta_l = first.get_lightness() - second.get_lightness();
+  let delta_a = first.get_a() - second.get_a();
+  let delta_b = first.get_b() - second.get_b();
+
+  let chroma_1 = first.get_a().hypot(first.get_b());
+  let chroma_2 = second.get_a().hypot(second.get_b());
+  let delta_c = chroma_1 - chroma_2;
+  let delta_h_squared = (delta_a.powi(2) + delta_b.powi(2) - delta_c.powi(2)).max(0.0);
+
+  let s_c = 1.0 + 0.045 * chroma_1;
+  let s_h = 1.0 + 0.015 * chroma_1;
+
+  (delta_l.powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
+
+// implemented after "The CIEDE2000 Color-Difference Formula" by Sharma, Wu and Dalal
+pub fn ciede2000(first: &Lab, second: &Lab) -> f64 {
+  let (l_1, a_1, b_1) = (first.get_lightness(), first.get_a(), first.get_b());
+  let (l_2, a_2, b_2) = (second.get_lightness(), second.get_a(), second.get_b());
+
+  let chroma_mean = (a_1.hypot(b_1) + a_2.hypot(b_2)) / 2.0;
+  let g = 0.5 * (1.0 - (chroma_mean.powi(7) / (chroma_mean.powi(7) + CHROMA_POWER)).sqrt());
+
+  let a_1 = (1.0 + g) * a_1;
+  let a_2 = (1.0 + g) * a_2;
+  let c_1 = a_1.hypot(b_1);
+  let c_2 = a_2.hypot(b_2);
+  let h_1 = hue_angle(a_1, b_1);
+  let h_2 = hue_angle(a_2, b_2);
+
+  let delta_l = l_2 - l_1;
+  let delta_c = c_2 - c_1;
+
+  let delta_h = if c_1 * c_2 == 0.0 {
+    0.0
+  } else if (h_2 - h_1).abs() <= 180.0 {
+    h_2 - h_1
+  } else if h_2 - h_1 > 180.0 {
+    h_2 - h_1 - 360.0
+  } else {
+    h_2 - h_1 + 360.0
+  };
+  let delta_h = 2.0 * (c_1 * c_2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+  let l_mean = (l_1 + l_2) / 2.0;
+  let c_mean = (c_1 + c_2) / 2.0;
+  let h_mean = if c_1 * c_2 == 0.0 {
+    h_1 + h_2
+  } else if (h_1 - h_2).abs() <= 180.0 {
+    (h_1 + h_2) / 2.0
+  } else if h_1 + h_2 < 360.0 {
+    (h_1 + h_2 + 360.0) / 2.0
+  } else {
+    (h_1 + h_2 - 360.0) / 2.0
+  };
+
+  let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+    + 0.24 * (2.0 * h_mean).to_radians().cos()
+    + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+    - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+  let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+  let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + CHROMA_POWER)).sqrt();
+
+  let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+  let s_c = 1.0 + 0.045 * c_mean;
+  let s_h = 1.0 + 0.015 * c_mean * t;
+  let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+  let lightness = delta_l / s_l;
+  let chroma = delta_c / s_c;
+  let hue = delta_h / s_h;
+
+  (lightness.powi(2) + chroma.powi(2) + hue.powi(2) + r_t * chroma * hue).sqrt()
+}
+
+// euclidean distance in OKLab, where 1 is the difference between black and white
+pub fn delta_e_ok(first: &Oklab, second: &Oklab) -> f64 {
+  let delta_l = first.get_lightness() - second.get_lightness();
+  let delta_a = first.get_a() - second.get_a();
+  let delta_b = first.get_b() - second.get_b();
+
+  (delta_l.powi(2) + delta_a.powi(2) + delta_b.powi(2)).sqrt()
+}
+
+fn hue_angle(a: f64, b: f64) -> f64 {
+  if a == 0.0 && b == 0.0 {
+    return 0.0;
+  }
+
+  b.atan2(a).to_degrees().rem_euclid(360.0)
+}
@@ -0,0 +1,102 @@
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::xyz_color::Xyz;
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::color_space::xyz_d65_to_linear_srgb;
+
+// lightness range of the OKLCH scale, pure white and black would lose the hue of the color
+const MAX_SCALE_LIGHTNESS: f64 = 0.97;
+const MIN_SCALE_LIGHTNESS: f64 = 0.2;
+// precision of the chroma reduction that brings a step back into sRGB
+const GAMUT_EPSILON: f64 = 0.0001;
+
+/// How the steps of a scale are derived from the base color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleKind {
+  // mixed toward white
+  Tint,
+  // mixed toward black
+  Shade,
+  // mixed toward middle gray
+  Tone,
+  // even OKLCH lightness steps from light to dark, the hue is kept and the chroma is reduced where sRGB can't show it
+  Lightness,
+}
+
+pub const SCALE_KINDS: [ScaleKind; 4] = [
+  ScaleKind::Tint,
+  ScaleKind::Shade,
+  ScaleKind::Tone,
+  ScaleKind::Lightness,
+];
+
+// linear interpolation of the gamma encoded channels, the alpha of the color is kept
+fn mix_toward(color: &Color, target: f64, amount: f64) -> Color {
+  let [red, green, blue] = color.srgb_values();
+  let [.., alpha] = color.hsva_values();
+  let mix = |channel: f64| channel + (target - channel) * amount;
+
+  Color::from_srgb_values(mix(red), mix(green), mix(blue), alpha)
+}
+
+// the base color comes first for tints, shades and tones and the target color itself is never reached
+pub fn scale(color: &Color, kind: ScaleKind, steps: usize) -> Vec<Color> {
+  match kind {
+    ScaleKind::Tint => mix_steps(color, 1.0, steps),
+    ScaleKind::Shade => mix_steps(color, 0.0, steps),
+    ScaleKind::Tone => mix_steps(color, 0.5, steps),
+    ScaleKind::Lightness => lightness_steps(color, steps),
+  }
+}
+
+fn mix_steps(color: &Color, target: f64, steps: usize) -> Vec<Color> {
+  (0..steps)
+    .map(|step| mix_toward(color, target, step as f64 / steps as f64))
+    .collect()
+}
+
+fn is_in_srgb(lightness: f64, chroma: f64, hue: f64) -> bool {
+  let hue = hue.to_radians();
+  let oklab = Oklab::from_values(lightness, chroma * hue.cos(), chroma * hue.sin(), 1.0);
+  let linear = xyz_d65_to_linear_srgb(Xyz::from_oklab(&oklab).get_values());
+
+  linear
+    .iter()
+    .all(|channel| *channel >= -GAMUT_EPSILON && *channel <= 1.0 + GAMUT_EPSILON)
+}
+
+// clipping the channels would change the lightness as well, so the chroma is lowered instead
+fn fit_chroma(lightness: f64, chroma: f64, hue: f64) -> f64 {
+  if is_in_srgb(lightness, chroma, hue) {
+    return chroma;
+  }
+
+  let mut low = 0.0;
+  let mut high = chroma;
+
+  while high - low > GAMUT_EPSILON {
+    let middle = (low + high) / 2.0;
+
+    if is_in_srgb(lightness, middle, hue) {
+      low = middle;
+    } else {
+      high = middle;
+    }
+  }
+
+  low
+}
+
+fn lightness_steps(color: &Color, steps: usize) -> Vec<Color> {
+  let oklch = color.to_oklch();
+  let range = MAX_SCALE_LIGHTNESS - MIN_SCALE_LIGHTNESS;
+  let last = steps.saturating_sub(1).max(1) as f64;
+
+  (0..steps)
+    .map(|step| {
+      let lightness = MAX_SCALE_LIGHTNESS - range * step as f64 / last;
+      let chroma = fit_chroma(lightness, oklch.get_chroma(), oklch.get_hue());
+
+      Color::from_oklch_values(lightness, chroma, oklch.get_hue(), oklch.get_alpha())
+    })
+    .collect()
+}
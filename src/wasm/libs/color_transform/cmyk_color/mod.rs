@@ -1,82 +1,132 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
+use crate::libs::color_transform::parse_error::{
+  format_alpha, parse_alpha, parse_component, split_components, ColorComponent, ParseColorError,
+};
 use crate::libs::color_transform::rgb_color::RGB;
 
-use crate::constants::{CMYK_REG_EXP, MAX_CMYK, MAX_RGB};
+use crate::constants::{MAX_ALPHA, MAX_CMYK, MAX_RGB, MIN_CMYK};
 
 #[derive(Serialize, Deserialize)]
 pub struct CMYK {
-  black: f32,
-  cyan: f32,
-  magenta: f32,
-  yellow: f32,
+  black: f64,
+  cyan: f64,
+  magenta: f64,
+  yellow: f64,
+  alpha: f64,
 }
 
 impl CMYK {
-  pub fn new(value: String) -> CMYK {
-    let mut cyan: f32 = 0.0;
-    let mut magenta: f32 = 0.0;
-    let mut yellow: f32 = 0.0;
-    let mut black: f32 = 0.0;
-
-    for cap in CMYK_REG_EXP.captures_iter(&value) {
-      cyan = cap[1].parse::<f32>().unwrap();
-      magenta = cap[2].parse::<f32>().unwrap();
-      yellow = cap[3].parse::<f32>().unwrap();
-      black = cap[4].parse::<f32>().unwrap();
-    }
-
-    CMYK {
-      cyan,
-      magenta,
-      yellow,
-      black,
-    }
-  }
-
   pub fn from_rgb(rgb: &RGB) -> CMYK {
-    let r_norm = rgb.get_red() / MAX_RGB;
-    let g_norm = rgb.get_green() / MAX_RGB;
-    let b_norm = rgb.get_blue() / MAX_RGB;
+    let max = f64::from(MAX_RGB);
+
+    let r_norm = rgb.get_red() / max;
+    let g_norm = rgb.get_green() / max;
+    let b_norm = rgb.get_blue() / max;
 
     let black_norm = 1.0 - (r_norm.max(g_norm.max(b_norm)));
-    let cyan_norm = (1.0 - r_norm - black_norm) / (1.0 - black_norm);
-    let magenta_norm = (1.0 - g_norm - black_norm) / (1.0 - black_norm);
-    let yellow_norm = (1.0 - b_norm - black_norm) / (1.0 - black_norm);
 
-    let black = (black_norm * MAX_CMYK).round();
-    let cyan = (cyan_norm * MAX_CMYK).round();
-    let magenta = (magenta_norm * MAX_CMYK).round();
-    let yellow = (yellow_norm * MAX_CMYK).round();
+    // pure black has no chromatic part and would divide by zero
+    let chromatic = |value: f64| {
+      if black_norm == 1.0 {
+        0.0
+      } else {
+        (1.0 - value - black_norm) / (1.0 - black_norm)
+      }
+    };
+
+    let max = f64::from(MAX_CMYK);
 
     CMYK {
-      black,
-      cyan,
-      magenta,
-      yellow,
+      black: black_norm * max,
+      cyan: chromatic(r_norm) * max,
+      magenta: chromatic(g_norm) * max,
+      yellow: chromatic(b_norm) * max,
+      alpha: rgb.get_alpha(),
     }
   }
 
-  pub fn get_cyan(&self) -> f32 {
+  pub fn get_cyan(&self) -> f64 {
     self.cyan
   }
 
-  pub fn get_magenta(&self) -> f32 {
+  pub fn get_magenta(&self) -> f64 {
     self.magenta
   }
 
-  pub fn get_yellow(&self) -> f32 {
+  pub fn get_yellow(&self) -> f64 {
     self.yellow
   }
 
-  pub fn get_black(&self) -> f32 {
+  pub fn get_black(&self) -> f64 {
     self.black
   }
 
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
   pub fn to_string(&self) -> String {
-    format!(
+    let value = format!(
       "{}%, {}%, {}%, {}%",
-      self.cyan, self.magenta, self.yellow, self.black
-    )
+      self.cyan.round(),
+      self.magenta.round(),
+      self.yellow.round(),
+      self.black.round()
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+
+  pub fn to_color_string(&self) -> String {
+    let value = format!(
+      "{}% {}% {}% {}%",
+      self.cyan.round(),
+      self.magenta.round(),
+      self.yellow.round(),
+      self.black.round()
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("device-cmyk({} / {})", value, format_alpha(self.alpha));
+    }
+
+    format!("device-cmyk({})", value)
+  }
+}
+
+impl FromStr for CMYK {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<CMYK, ParseColorError> {
+    let (parts, alpha) = split_components(value, 4)?;
+
+    let cyan = parse_component(parts[0], ColorComponent::Cyan, "%", MIN_CMYK, MAX_CMYK)?;
+    let magenta = parse_component(parts[1], ColorComponent::Magenta, "%", MIN_CMYK, MAX_CMYK)?;
+    let yellow = parse_component(parts[2], ColorComponent::Yellow, "%", MIN_CMYK, MAX_CMYK)?;
+    let black = parse_component(parts[3], ColorComponent::Black, "%", MIN_CMYK, MAX_CMYK)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(CMYK {
+      cyan,
+      magenta,
+      yellow,
+      black,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for CMYK {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<CMYK, ParseColorError> {
+    value.parse()
   }
 }
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
+use crate::libs::color_transform::parse_error::{ColorComponent, ParseColorError};
 use crate::libs::color_transform::rgb_color::RGB;
 
-use crate::constants::HEX_SHORT_REG_EXP;
+use crate::constants::{HEX_LONG_REG_EXP, HEX_SHORT_REG_EXP, MAX_ALPHA, MAX_RGB};
 
 #[derive(Serialize, Deserialize)]
 pub struct Hex {
@@ -10,12 +13,8 @@ pub struct Hex {
 }
 
 impl Hex {
-  pub fn new(value: String) -> Hex {
-    Hex { value }
-  }
-
-  fn value_to_hex_part(value: i32) -> String {
-    let part = format!("{:X}", value);
+  fn value_to_hex_part(value: f64) -> String {
+    let part = format!("{:X}", value.round() as i32);
     if part.len() == 1 {
       return format!("0{}", part);
     }
@@ -24,11 +23,16 @@ impl Hex {
   }
 
   pub fn from_rgb(rgb: &RGB) -> Hex {
-    let r_part = Hex::value_to_hex_part(rgb.get_red() as i32);
-    let g_part = Hex::value_to_hex_part(rgb.get_green() as i32);
-    let b_part = Hex::value_to_hex_part(rgb.get_blue() as i32);
+    let r_part = Hex::value_to_hex_part(rgb.get_red());
+    let g_part = Hex::value_to_hex_part(rgb.get_green());
+    let b_part = Hex::value_to_hex_part(rgb.get_blue());
+
+    let mut value = format!("#{}{}{}", r_part, g_part, b_part);
+    if rgb.get_alpha() < f64::from(MAX_ALPHA) {
+      let a_part = Hex::value_to_hex_part(rgb.get_alpha() * f64::from(MAX_RGB));
+      value.push_str(&a_part);
+    }
 
-    let value = format!("#{}{}{}", r_part, g_part, b_part);
     Hex { value }
   }
 
@@ -39,7 +43,7 @@ impl Hex {
   pub fn to_string(&self) -> String {
     let mut value = if HEX_SHORT_REG_EXP.is_match(&self.value) {
       HEX_SHORT_REG_EXP
-        .replace_all(&self.value, "#$r$r$g$g$b$b")
+        .replace_all(&self.value, "#$r$r$g$g$b$b$a$a")
         .to_string()
     } else {
       self.value.to_string()
@@ -48,4 +52,65 @@ impl Hex {
     value.make_ascii_lowercase();
     value
   }
+
+  pub fn to_color_string(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl FromStr for Hex {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Hex, ParseColorError> {
+    let value = value.trim();
+    if HEX_SHORT_REG_EXP.is_match(value) || HEX_LONG_REG_EXP.is_match(value) {
+      return Ok(Hex {
+        value: value.to_string(),
+      });
+    }
+
+    let digits: Vec<char> = value.strip_prefix('#').unwrap_or(value).chars().collect();
+    let channel_len = match digits.len() {
+      3 | 4 => 1,
+      6 | 8 => 2,
+      _ => {
+        return Err(ParseColorError::Malformed {
+          component: ColorComponent::Hex,
+          value: value.to_string(),
+        })
+      }
+    };
+
+    // point at the first channel which has a non hex digit
+    let channels = [
+      ColorComponent::Red,
+      ColorComponent::Green,
+      ColorComponent::Blue,
+      ColorComponent::Alpha,
+    ];
+
+    let count = digits.len() / channel_len;
+    for (index, component) in channels.iter().take(count).enumerate() {
+      let channel: String = digits[index * channel_len..(index + 1) * channel_len]
+        .iter()
+        .collect();
+
+      if !channel.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::Malformed {
+          component: *component,
+          value: channel,
+        });
+      }
+    }
+
+    Err(ParseColorError::UnknownFormat(value.to_string()))
+  }
+}
+
+impl TryFrom<&str> for Hex {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<Hex, ParseColorError> {
+    value.parse()
+  }
 }
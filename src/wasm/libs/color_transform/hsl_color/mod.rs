@@ -1,41 +1,42 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
-use crate::constants::{HSV_REG_EXP, MAX_SVL};
+use crate::constants::{MAX_ALPHA, MAX_H, MAX_SVL, MIN_HSV};
 use crate::libs::color_transform::hsv_color::HSV;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, parse_alpha, parse_component, parse_hue, split_components, ColorComponent,
+  ParseColorError,
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct HSL {
-  hue: f32,
-  saturation: f32,
-  lightness: f32,
+  hue: f64,
+  saturation: f64,
+  lightness: f64,
+  alpha: f64,
 }
 
 impl HSL {
-  pub fn new(hsl: String) -> HSL {
-    let mut hue: f32 = 0.0;
-    let mut saturation: f32 = 0.0;
-    let mut lightness: f32 = 0.0;
-
-    for cap in HSV_REG_EXP.captures_iter(&hsl) {
-      hue = cap[1].parse::<f32>().unwrap();
-      saturation = cap[2].parse::<f32>().unwrap();
-      lightness = cap[3].parse::<f32>().unwrap();
-    }
-
+  // h = [0,360], s = [0,100], l = [0,100], a = [0,1]
+  pub fn from_values(h: f64, s: f64, l: f64, a: f64) -> HSL {
     HSL {
-      hue,
-      saturation,
-      lightness,
+      hue: h,
+      saturation: s,
+      lightness: l,
+      alpha: a,
     }
   }
 
   pub fn from_hsv(hsv: &HSV) -> HSL {
-    let s_norm = hsv.get_saturation() / MAX_SVL;
-    let v_norm = hsv.get_value() / MAX_SVL;
+    let max = f64::from(MAX_SVL);
+
+    let s_norm = hsv.get_saturation() / max;
+    let v_norm = hsv.get_value() / max;
 
-    let lightness: f32 = (2.0 - s_norm) * v_norm / 2.0;
+    let lightness = (2.0 - s_norm) * v_norm / 2.0;
 
-    let mut saturation: f32 = s_norm;
+    let mut saturation = s_norm;
 
     if lightness != 0.0 {
       if lightness == 1.0 {
@@ -49,24 +50,83 @@ impl HSL {
 
     HSL {
       hue: hsv.get_hue(),
-      saturation: (saturation * MAX_SVL).round(),
-      lightness: (lightness * MAX_SVL).round(),
+      saturation: saturation * max,
+      lightness: lightness * max,
+      alpha: hsv.get_alpha(),
     }
   }
 
-  pub fn get_hue(&self) -> f32 {
+  pub fn get_hue(&self) -> f64 {
     self.hue
   }
 
-  pub fn get_saturation(&self) -> f32 {
+  pub fn get_saturation(&self) -> f64 {
     self.saturation
   }
 
-  pub fn get_lightness(&self) -> f32 {
+  pub fn get_lightness(&self) -> f64 {
     self.lightness
   }
 
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
   pub fn to_string(&self) -> String {
-    format!("{}°, {}%, {}%", self.hue, self.saturation, self.lightness)
+    let value = format!(
+      "{}°, {}%, {}%",
+      self.hue.round(),
+      self.saturation.round(),
+      self.lightness.round()
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+
+  pub fn to_color_string(&self) -> String {
+    let value = format!(
+      "{}, {}%, {}%",
+      self.hue.round(),
+      self.saturation.round(),
+      self.lightness.round()
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("hsla({}, {})", value, format_alpha(self.alpha));
+    }
+
+    format!("hsl({})", value)
+  }
+}
+
+impl FromStr for HSL {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<HSL, ParseColorError> {
+    let (parts, alpha) = split_components(value, 3)?;
+
+    let hue = parse_hue(parts[0], MIN_HSV, MAX_H)?;
+    let saturation = parse_component(parts[1], ColorComponent::Saturation, "%", MIN_HSV, MAX_SVL)?;
+    let lightness = parse_component(parts[2], ColorComponent::Lightness, "%", MIN_HSV, MAX_SVL)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(HSL {
+      hue,
+      saturation,
+      lightness,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for HSL {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<HSL, ParseColorError> {
+    value.parse()
   }
 }
@@ -1,68 +1,60 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
-use crate::constants::{HSV_REG_EXP, MAX_RGB, MAX_SVL};
+use crate::constants::{MAX_ALPHA, MAX_H, MAX_RGB, MAX_SVL, MIN_HSV};
 use crate::libs::color_transform::hsl_color::HSL;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, parse_alpha, parse_component, parse_hue, split_components, ColorComponent,
+  ParseColorError,
+};
 use crate::libs::color_transform::rgb_color::RGB;
 
 #[derive(Serialize, Deserialize)]
 pub struct HSV {
-  hue: f32,
-  saturation: f32,
-  value: f32,
+  hue: f64,
+  saturation: f64,
+  value: f64,
+  alpha: f64,
 }
 
 impl HSV {
-  pub fn new(hsv: String) -> HSV {
-    let mut hue: f32 = 0.0;
-    let mut saturation: f32 = 0.0;
-    let mut value: f32 = 0.0;
-
-    for cap in HSV_REG_EXP.captures_iter(&hsv) {
-      hue = cap[1].parse::<f32>().unwrap();
-      saturation = cap[2].parse::<f32>().unwrap();
-      value = cap[3].parse::<f32>().unwrap();
-    }
-
-    HSV {
-      hue,
-      saturation,
-      value,
-    }
-  }
-
-  // h = [0,360], s = [0,100], v = [0,100]
-  pub fn from_values(h: f32, s: f32, v: f32) -> HSV {
+  // h = [0,360], s = [0,100], v = [0,100], a = [0,1]
+  pub fn from_values(h: f64, s: f64, v: f64, a: f64) -> HSV {
     HSV {
       hue: h,
       saturation: s,
       value: v,
+      alpha: a,
     }
   }
 
   pub fn from_rgb(rgb: &RGB) -> HSV {
-    let r_norm = rgb.get_red() / MAX_RGB;
-    let g_norm = rgb.get_green() / MAX_RGB;
-    let b_norm = rgb.get_blue() / MAX_RGB;
+    let max = f64::from(MAX_RGB);
+
+    let r_norm = rgb.get_red() / max;
+    let g_norm = rgb.get_green() / max;
+    let b_norm = rgb.get_blue() / max;
 
     // h, s, v = hue, saturation, value
     let cmax = r_norm.max(g_norm.max(b_norm)); // maximum of r, g, b
     let cmin = r_norm.min(g_norm.min(b_norm)); // minimum of r, g, b
     let diff = cmax - cmin; // diff of cmax and cmin.
 
-    let mut h: f32 = -1.0;
+    let mut h: f64 = -1.0;
 
     // if cmax and cmax are equal then h = 0
     if cmax == cmin {
       h = 0.0;
     } else if cmax == r_norm {
       // if cmax equal r then compute h
-      h = (60.0 * ((g_norm - b_norm) / diff) + 360.0).round() % 360.0;
+      h = (60.0 * ((g_norm - b_norm) / diff) + 360.0) % 360.0;
     } else if cmax == g_norm {
       // if cmax equal g then compute h
-      h = (60.0 * ((b_norm - r_norm) / diff) + 120.0).round() % 360.0;
+      h = (60.0 * ((b_norm - r_norm) / diff) + 120.0) % 360.0;
     } else if cmax == b_norm {
       // if cmax equal b then compute h
-      h = (60.0 * ((r_norm - g_norm) / diff) + 240.0).round() % 360.0;
+      h = (60.0 * ((r_norm - g_norm) / diff) + 240.0) % 360.0;
     }
 
     // if cmax equal zero
@@ -73,20 +65,22 @@ impl HSV {
     };
 
     // compute v
-    let v: f32 = cmax * 100.0;
+    let v = cmax * 100.0;
 
     HSV {
       hue: h,
       saturation: s,
       value: v,
+      alpha: rgb.get_alpha(),
     }
   }
 
   pub fn from_hsl(hsl: &HSL) -> HSV {
+    let max = f64::from(MAX_SVL);
     let h = hsl.get_hue();
 
-    let l_norm = hsl.get_lightness() * 2.0 / MAX_SVL;
-    let mut s_norm = hsl.get_saturation() / MAX_SVL;
+    let l_norm = hsl.get_lightness() * 2.0 / max;
+    let mut s_norm = hsl.get_saturation() / max;
     if l_norm <= 1.0 {
       s_norm *= l_norm;
     } else {
@@ -94,28 +88,97 @@ impl HSV {
     }
 
     let v = (l_norm + s_norm) / 2.0;
-    let s = (2.0 * s_norm) / (l_norm + s_norm);
+
+    // black keeps its saturation, otherwise it would be lost on the way back to HSL
+    let s = if v == 0.0 {
+      hsl.get_saturation() / max
+    } else {
+      (2.0 * s_norm) / (l_norm + s_norm)
+    };
 
     HSV {
       hue: h,
-      saturation: (s * MAX_SVL).round(),
-      value: (v * MAX_SVL).round(),
+      saturation: s * max,
+      value: v * max,
+      alpha: hsl.get_alpha(),
     }
   }
 
-  pub fn get_hue(&self) -> f32 {
+  pub fn get_hue(&self) -> f64 {
     self.hue
   }
 
-  pub fn get_saturation(&self) -> f32 {
+  pub fn get_saturation(&self) -> f64 {
     self.saturation
   }
 
-  pub fn get_value(&self) -> f32 {
+  pub fn get_value(&self) -> f64 {
     self.value
   }
 
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
   pub fn to_string(&self) -> String {
-    format!("{}°, {}%, {}%", self.hue, self.saturation, self.value)
+    let value = format!(
+      "{}°, {}%, {}%",
+      self.hue.round(),
+      self.saturation.round(),
+      self.value.round()
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+
+  // CSS has no HSV notation, but HWB describes the same color with whiteness and blackness
+  pub fn to_color_string(&self) -> String {
+    let max = f64::from(MAX_SVL);
+
+    let s_norm = self.saturation / max;
+    let v_norm = self.value / max;
+
+    let whiteness = ((1.0 - s_norm) * v_norm * max).round();
+    let blackness = ((1.0 - v_norm) * max).round();
+
+    let value = format!("{} {}% {}%", self.hue.round(), whiteness, blackness);
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("hwb({} / {})", value, format_alpha(self.alpha));
+    }
+
+    format!("hwb({})", value)
+  }
+}
+
+impl FromStr for HSV {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<HSV, ParseColorError> {
+    let (parts, alpha) = split_components(value, 3)?;
+
+    let hue = parse_hue(parts[0], MIN_HSV, MAX_H)?;
+    let saturation = parse_component(parts[1], ColorComponent::Saturation, "%", MIN_HSV, MAX_SVL)?;
+    let value = parse_component(parts[2], ColorComponent::Value, "%", MIN_HSV, MAX_SVL)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(HSV {
+      hue,
+      saturation,
+      value,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for HSV {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<HSV, ParseColorError> {
+    value.parse()
   }
 }
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::MAX_H;
+
+/// Which way round the hue wheel polar spaces interpolate, as in CSS `color-mix()`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HueInterpolation {
+  Shorter,
+  Longer,
+  Increasing,
+  Decreasing,
+}
+
+/// Color space two colors are interpolated in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationSpace {
+  Srgb,
+  LinearRgb,
+  Hsl(HueInterpolation),
+  Hsv(HueInterpolation),
+  Lab,
+  Oklab,
+  Oklch(HueInterpolation),
+}
+
+pub fn lerp(first: f64, second: f64, t: f64) -> f64 {
+  first + (second - first) * t
+}
+
+// components are premultiplied by alpha first, so a transparent color doesn't tint the other one
+pub fn mix_components<const N: usize>(
+  first: [f64; N],
+  first_alpha: f64,
+  second: [f64; N],
+  second_alpha: f64,
+  t: f64,
+) -> [f64; N] {
+  let alpha = lerp(first_alpha, second_alpha, t);
+  let mut result = [0.0; N];
+
+  for (index, value) in result.iter_mut().enumerate() {
+    *value = if alpha == 0.0 {
+      lerp(first[index], second[index], t)
+    } else {
+      lerp(first[index] * first_alpha, second[index] * second_alpha, t) / alpha
+    };
+  }
+
+  result
+}
+
+impl HueInterpolation {
+  // a missing hue (achromatic color) takes the hue of the other color
+  pub fn interpolate(self, first: Option<f64>, second: Option<f64>, t: f64) -> f64 {
+    let max = f64::from(MAX_H);
+
+    let (mut first, mut second) = match (first, second) {
+      (Some(first), Some(second)) => (first.rem_euclid(max), second.rem_euclid(max)),
+      (Some(hue), None) | (None, Some(hue)) => (hue, hue),
+      (None, None) => (0.0, 0.0),
+    };
+
+    let diff = second - first;
+    match self {
+      HueInterpolation::Shorter => {
+        if diff > max / 2.0 {
+          first += max;
+        } else if diff < -max / 2.0 {
+          second += max;
+        }
+      }
+
+      HueInterpolation::Longer => {
+        if diff > 0.0 && diff < max / 2.0 {
+          first += max;
+        } else if diff > -max / 2.0 && diff <= 0.0 {
+          second += max;
+        }
+      }
+
+      HueInterpolation::Increasing => {
+        if second < first {
+          second += max;
+        }
+      }
+
+      HueInterpolation::Decreasing => {
+        if first < second {
+          first += max;
+        }
+      }
+    }
+
+    lerp(first, second, t).rem_euclid(max)
+  }
+}
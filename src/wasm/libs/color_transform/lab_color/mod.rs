@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::constants::{MAX_ALPHA, MAX_LAB_AB, MAX_LAB_L, MIN_LAB_AB, MIN_LAB_L};
+use crate::libs::color_transform::lch_color::Lch;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, format_decimal, parse_alpha, parse_component, parse_signed_component,
+  split_components, ColorComponent, ParseColorError,
+};
+use crate::libs::color_transform::rgb_color::RGB;
+use crate::libs::color_transform::xyz_color::{Adaptation, WhitePoint, Xyz};
+use crate::libs::css_color::color_space::{xyz_to_lab, D65_WHITE};
+
+// CIELAB relative to the D65 white point of sRGB
+#[derive(Serialize, Deserialize)]
+pub struct Lab {
+  lightness: f64,
+  a: f64,
+  b: f64,
+  alpha: f64,
+}
+
+impl Lab {
+  // l = [0,100], a = [-128,128], b = [-128,128], alpha = [0,1]
+  pub fn from_values(l: f64, a: f64, b: f64, alpha: f64) -> Lab {
+    Lab {
+      lightness: l,
+      a,
+      b,
+      alpha,
+    }
+  }
+
+  pub fn from_rgb(rgb: &RGB) -> Lab {
+    Lab::from_xyz(&Xyz::from_rgb(rgb))
+  }
+
+  // other white points are adapted to D65 first
+  pub fn from_xyz(xyz: &Xyz) -> Lab {
+    let xyz = xyz.adapt(WhitePoint::D65, Adaptation::Bradford);
+    let lab = xyz_to_lab(xyz.get_values(), D65_WHITE);
+
+    Lab {
+      lightness: lab[0],
+      a: lab[1],
+      b: lab[2],
+      alpha: xyz.get_alpha(),
+    }
+  }
+
+  pub fn from_lch(lch: &Lch) -> Lab {
+    let hue = lch.get_hue().to_radians();
+
+    Lab {
+      lightness: lch.get_lightness(),
+      a: lch.get_chroma() * hue.cos(),
+      b: lch.get_chroma() * hue.sin(),
+      alpha: lch.get_alpha(),
+    }
+  }
+
+  pub fn get_lightness(&self) -> f64 {
+    self.lightness
+  }
+
+  pub fn get_a(&self) -> f64 {
+    self.a
+  }
+
+  pub fn get_b(&self) -> f64 {
+    self.b
+  }
+
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
+  pub fn to_string(&self) -> String {
+    let value = format!(
+      "{}, {}, {}",
+      format_decimal(self.lightness, 2),
+      format_decimal(self.a, 2),
+      format_decimal(self.b, 2)
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+}
+
+impl FromStr for Lab {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Lab, ParseColorError> {
+    let (parts, alpha) = split_components(value, 3)?;
+
+    let lightness = parse_component(
+      parts[0],
+      ColorComponent::Lightness,
+      "",
+      MIN_LAB_L,
+      MAX_LAB_L,
+    )?;
+    let a = parse_signed_component(
+      parts[1],
+      ColorComponent::GreenRed,
+      "",
+      MIN_LAB_AB,
+      MAX_LAB_AB,
+    )?;
+    let b = parse_signed_component(
+      parts[2],
+      ColorComponent::BlueYellow,
+      "",
+      MIN_LAB_AB,
+      MAX_LAB_AB,
+    )?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(Lab {
+      lightness,
+      a,
+      b,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for Lab {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<Lab, ParseColorError> {
+    value.parse()
+  }
+}
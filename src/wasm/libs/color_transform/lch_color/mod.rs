@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::constants::{MAX_ALPHA, MAX_H, MAX_LAB_L, MAX_LCH_C, MIN_HSV, MIN_LAB_L, MIN_LCH_C};
+use crate::libs::color_transform::lab_color::Lab;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, format_decimal, parse_alpha, parse_component, parse_hue, split_components,
+  ColorComponent, ParseColorError,
+};
+
+// cylindrical form of CIELAB: lightness, chroma and hue
+#[derive(Serialize, Deserialize)]
+pub struct Lch {
+  lightness: f64,
+  chroma: f64,
+  hue: f64,
+  alpha: f64,
+}
+
+impl Lch {
+  pub fn from_lab(lab: &Lab) -> Lch {
+    let chroma = lab.get_a().hypot(lab.get_b());
+    let hue = lab.get_b().atan2(lab.get_a()).to_degrees();
+
+    Lch {
+      lightness: lab.get_lightness(),
+      chroma,
+      hue: hue.rem_euclid(f64::from(MAX_H)),
+      alpha: lab.get_alpha(),
+    }
+  }
+
+  pub fn get_lightness(&self) -> f64 {
+    self.lightness
+  }
+
+  pub fn get_chroma(&self) -> f64 {
+    self.chroma
+  }
+
+  pub fn get_hue(&self) -> f64 {
+    self.hue
+  }
+
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
+  pub fn to_string(&self) -> String {
+    let value = format!(
+      "{}, {}, {}°",
+      format_decimal(self.lightness, 2),
+      format_decimal(self.chroma, 2),
+      format_decimal(self.hue, 2)
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+}
+
+impl FromStr for Lch {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Lch, ParseColorError> {
+    let (parts, alpha) = split_components(value, 3)?;
+
+    let lightness = parse_component(
+      parts[0],
+      ColorComponent::Lightness,
+      "",
+      MIN_LAB_L,
+      MAX_LAB_L,
+    )?;
+    let chroma = parse_component(parts[1], ColorComponent::Chroma, "", MIN_LCH_C, MAX_LCH_C)?;
+    let hue = parse_hue(parts[2], MIN_HSV, MAX_H)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(Lch {
+      lightness,
+      chroma,
+      hue,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for Lch {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<Lch, ParseColorError> {
+    value.parse()
+  }
+}
@@ -1,152 +1,447 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 pub mod cmyk_color;
 pub mod hex_color;
 pub mod hsl_color;
 pub mod hsv_color;
+pub mod interpolation;
+pub mod lab_color;
+pub mod lch_color;
+pub mod oklab_color;
+pub mod oklch_color;
+pub mod parse_error;
 pub mod rgb_color;
+pub mod xyz_color;
 
 use cmyk_color::CMYK;
 use hex_color::Hex;
 use hsl_color::HSL;
 use hsv_color::HSV;
+use interpolation::{lerp, mix_components, InterpolationSpace};
+use lab_color::Lab;
+use lch_color::Lch;
+use oklab_color::Oklab;
+use oklch_color::Oklch;
+use parse_error::ParseColorError;
 use rgb_color::RGB;
+use xyz_color::Xyz;
 
-#[derive(Serialize, Deserialize)]
+use crate::constants::{MAX_ALPHA, MAX_RGB, MAX_SVL, NOTATION_REG_EXP};
+use crate::libs::css_color::color_space::{linear_to_srgb, srgb_to_linear};
+use crate::libs::css_color::{self, CssFormat};
+
+// chroma or saturation below it is considered achromatic when mixing
+const ACHROMATIC_EPSILON: f64 = 0.0001;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Color {
-  hex: Hex,
-  hsv: HSV,
-  rgb: RGB,
-  cmyk: CMYK,
-  hsl: HSL,
+  // gamma encoded sRGB channels in [0,1], every model is derived from them on demand
+  red: f64,
+  green: f64,
+  blue: f64,
+  alpha: f64,
+  // greys and black have no hue and saturation of their own, so the ones they were picked with are kept
+  hue: f64,
+  saturation: f64,
 }
 
 impl Color {
-  pub fn from_hsv_values(h: f32, s: f32, v: f32) -> Color {
-    let hsv = HSV::from_values(h, s, v);
-    let rgb = RGB::from_hsv(&hsv);
-    let hex = Hex::from_rgb(&rgb);
-    let cmyk = CMYK::from_rgb(&rgb);
-    let hsl = HSL::from_hsv(&hsv);
+  fn from_models(rgb: &RGB, hsv: &HSV) -> Color {
+    let max = f64::from(MAX_RGB);
 
     Color {
-      hex,
-      hsv,
-      rgb,
-      cmyk,
-      hsl,
+      red: rgb.get_red() / max,
+      green: rgb.get_green() / max,
+      blue: rgb.get_blue() / max,
+      alpha: rgb.get_alpha(),
+      hue: hsv.get_hue(),
+      saturation: hsv.get_saturation(),
     }
   }
 
-  pub fn from_hex(value: String) -> Color {
-    let hex = Hex::new(value);
-    let rgb = RGB::from_hex(&hex);
-    let hsv = HSV::from_rgb(&rgb);
-    let cmyk = CMYK::from_rgb(&rgb);
-    let hsl = HSL::from_hsv(&hsv);
+  fn from_rgb_model(rgb: &RGB) -> Color {
+    Color::from_models(rgb, &HSV::from_rgb(rgb))
+  }
 
-    Color {
-      hex,
-      rgb,
-      hsv,
-      cmyk,
-      hsl,
-    }
+  fn from_hsv_model(hsv: &HSV) -> Color {
+    Color::from_models(&RGB::from_hsv(hsv), hsv)
   }
 
-  pub fn from_rgb(value: String) -> Color {
-    let rgb = RGB::new(value);
-    let hex = Hex::from_rgb(&rgb);
-    let hsv = HSV::from_rgb(&rgb);
-    let cmyk = CMYK::from_rgb(&rgb);
-    let hsl = HSL::from_hsv(&hsv);
+  pub fn from_hsv_values(h: f32, s: f32, v: f32) -> Color {
+    Color::from_hsva_values(h, s, v, MAX_ALPHA)
+  }
 
-    Color {
-      hex,
-      rgb,
-      hsv,
-      cmyk,
-      hsl,
-    }
+  // h = [0,360], s = [0,100], v = [0,100], a = [0,1]
+  pub fn from_hsva_values(h: f32, s: f32, v: f32, a: f32) -> Color {
+    Color::from_hsv_model(&HSV::from_values(
+      f64::from(h),
+      f64::from(s),
+      f64::from(v),
+      f64::from(a),
+    ))
   }
 
-  pub fn from_cmyk(value: String) -> Color {
-    let cmyk = CMYK::new(value);
-    let rgb = RGB::from_cmyk(&cmyk);
-    let hex = Hex::from_rgb(&rgb);
-    let hsv = HSV::from_rgb(&rgb);
-    let hsl = HSL::from_hsv(&hsv);
+  // r = [0,255], g = [0,255], b = [0,255]
+  pub fn from_rgb_values(r: f32, g: f32, b: f32) -> Color {
+    Color::from_rgba_values(r, g, b, MAX_ALPHA)
+  }
 
-    Color {
-      hex,
-      rgb,
-      hsv,
-      cmyk,
-      hsl,
-    }
+  // r = [0,255], g = [0,255], b = [0,255], a = [0,1]
+  pub fn from_rgba_values(r: f32, g: f32, b: f32, a: f32) -> Color {
+    Color::from_rgb_model(&RGB::from_values(
+      f64::from(r),
+      f64::from(g),
+      f64::from(b),
+      f64::from(a),
+    ))
   }
 
-  pub fn from_hsv(value: String) -> Color {
-    let hsv = HSV::new(value);
-    let rgb = RGB::from_hsv(&hsv);
-    let hex = Hex::from_rgb(&rgb);
-    let cmyk = CMYK::from_rgb(&rgb);
-    let hsl = HSL::from_hsv(&hsv);
+  // r = [0,1], g = [0,1], b = [0,1], a = [0,1]
+  pub fn from_srgb_values(r: f64, g: f64, b: f64, a: f64) -> Color {
+    let max = f64::from(MAX_RGB);
+    Color::from_rgb_model(&RGB::from_values(r * max, g * max, b * max, a))
+  }
 
-    Color {
-      hex,
-      hsv,
-      rgb,
-      cmyk,
-      hsl,
+  // l = [0,1], c = [0,0.4], h = [0,360], a = [0,1], colors outside of sRGB are clipped
+  pub fn from_oklch_values(l: f64, c: f64, h: f64, a: f64) -> Color {
+    let oklch = Oklch::from_values(l, c, h, a);
+    Color::from_rgb_model(&RGB::from_oklab(&Oklab::from_oklch(&oklch)))
+  }
+
+  pub fn from_xyz(xyz: &Xyz) -> Color {
+    Color::from_rgb_model(&RGB::from_xyz(xyz))
+  }
+
+  pub fn from_hex(value: String) -> Result<Color, ParseColorError> {
+    let hex = value.parse::<Hex>()?;
+    Ok(Color::from_rgb_model(&RGB::from_hex(&hex)))
+  }
+
+  pub fn from_rgb(value: String) -> Result<Color, ParseColorError> {
+    let rgb = value.parse::<RGB>()?;
+    Ok(Color::from_rgb_model(&rgb))
+  }
+
+  pub fn from_cmyk(value: String) -> Result<Color, ParseColorError> {
+    let cmyk = value.parse::<CMYK>()?;
+    Ok(Color::from_rgb_model(&RGB::from_cmyk(&cmyk)))
+  }
+
+  pub fn from_hsv(value: String) -> Result<Color, ParseColorError> {
+    let hsv = value.parse::<HSV>()?;
+    Ok(Color::from_hsv_model(&hsv))
+  }
+
+  pub fn from_hsl(value: String) -> Result<Color, ParseColorError> {
+    let hsl = value.parse::<HSL>()?;
+    Ok(Color::from_hsv_model(&HSV::from_hsl(&hsl)))
+  }
+
+  pub fn from_lab(value: String) -> Result<Color, ParseColorError> {
+    let lab = value.parse::<Lab>()?;
+    Ok(Color::from_rgb_model(&RGB::from_lab(&lab)))
+  }
+
+  pub fn from_lch(value: String) -> Result<Color, ParseColorError> {
+    let lch = value.parse::<Lch>()?;
+    Ok(Color::from_rgb_model(&RGB::from_lab(&Lab::from_lch(&lch))))
+  }
+
+  pub fn from_oklab(value: String) -> Result<Color, ParseColorError> {
+    let oklab = value.parse::<Oklab>()?;
+    Ok(Color::from_rgb_model(&RGB::from_oklab(&oklab)))
+  }
+
+  pub fn from_oklch(value: String) -> Result<Color, ParseColorError> {
+    let oklch = value.parse::<Oklch>()?;
+    Ok(Color::from_rgb_model(&RGB::from_oklab(&Oklab::from_oklch(
+      &oklch,
+    ))))
+  }
+
+  // accepts a hex value, one of the "rgb(...)", "cmyk(...)", "hsv(...)" and "hsl(...)" notations
+  // or anything the CSS parser understands
+  pub fn parse(value: &str) -> Result<Color, ParseColorError> {
+    let value = value.trim();
+
+    let cap = match NOTATION_REG_EXP.captures(value) {
+      Some(cap) => cap,
+      None => {
+        return Color::from_hex(value.to_string())
+          .or_else(|_| css_color::parse(value))
+          .map_err(|_| ParseColorError::UnknownFormat(value.to_string()))
+      }
+    };
+
+    let components = cap[2].to_string();
+    let color = match cap[1].to_ascii_lowercase().as_str() {
+      "rgb" => Color::from_rgb(components),
+      "cmyk" => Color::from_cmyk(components),
+      "hsv" => Color::from_hsv(components),
+      "hsl" => Color::from_hsl(components),
+      _ => return css_color::parse(value),
+    };
+
+    // values in range but written the CSS way, e.g. "rgb(100% 0% 0%)", are left to the CSS
+    // parser, the error of the notation is kept when it fails as well
+    match color {
+      Err(ParseColorError::Malformed { .. }) | Err(ParseColorError::ComponentCount { .. }) => {
+        color.or_else(|error| css_color::parse(value).map_err(|_| error))
+      }
+      color => color,
     }
   }
 
-  pub fn from_hsl(value: String) -> Color {
-    let hsl = HSL::new(value);
-    let hsv = HSV::from_hsl(&hsl);
-    let rgb = RGB::from_hsv(&hsv);
-    let hex = Hex::from_rgb(&rgb);
-    let cmyk = CMYK::from_rgb(&rgb);
+  fn rgb(&self) -> RGB {
+    let max = f64::from(MAX_RGB);
+    RGB::from_values(
+      self.red * max,
+      self.green * max,
+      self.blue * max,
+      self.alpha,
+    )
+  }
+
+  fn hsv(&self) -> HSV {
+    let value = self.red.max(self.green.max(self.blue)) * f64::from(MAX_SVL);
+    HSV::from_values(self.hue, self.saturation, value, self.alpha)
+  }
+
+  fn hsl(&self) -> HSL {
+    HSL::from_hsv(&self.hsv())
+  }
+
+  fn cmyk(&self) -> CMYK {
+    CMYK::from_rgb(&self.rgb())
+  }
+
+  fn hex(&self) -> Hex {
+    Hex::from_rgb(&self.rgb())
+  }
+
+  pub fn to_lab(&self) -> Lab {
+    Lab::from_rgb(&self.rgb())
+  }
+
+  pub fn to_oklab(&self) -> Oklab {
+    Oklab::from_rgb(&self.rgb())
+  }
+
+  pub fn to_oklch(&self) -> Oklch {
+    Oklch::from_oklab(&self.to_oklab())
+  }
+
+  // relative to D65, use Xyz::adapt for other white points
+  pub fn to_xyz(&self) -> Xyz {
+    Xyz::from_rgb(&self.rgb())
+  }
+
+  // gamma encoded sRGB channels in [0,1], without any rounding
+  pub fn srgb_values(&self) -> [f64; 3] {
+    [self.red, self.green, self.blue]
+  }
+
+  pub fn relative_luminance(&self) -> f64 {
+    self.rgb().relative_luminance()
+  }
+
+  // h = [0,360], s = [0,100], v = [0,100], a = [0,1], without any rounding
+  pub fn hsva_values(&self) -> [f64; 4] {
+    [
+      self.hue,
+      self.saturation,
+      self.hsv().get_value(),
+      self.alpha,
+    ]
+  }
 
+  // the sRGB channels are kept as they are
+  pub fn with_alpha(&self, alpha: f64) -> Color {
     Color {
-      hex,
-      hsv,
-      rgb,
-      cmyk,
-      hsl,
+      alpha,
+      ..self.clone()
     }
   }
 
+  // h = [0,360], s = [0,100], v = [0,100], the opacity is kept
+  pub fn with_hsv(&self, h: f64, s: f64, v: f64) -> Color {
+    Color::from_hsv_model(&HSV::from_values(h, s, v, self.alpha))
+  }
+
   pub fn get_hue(&self) -> f32 {
-    self.hsv.get_hue()
+    self.hue as f32
   }
 
   pub fn get_saturation(&self) -> f32 {
-    self.hsv.get_saturation()
+    self.saturation as f32
   }
 
   pub fn get_value(&self) -> f32 {
-    self.hsv.get_value()
+    self.hsv().get_value() as f32
+  }
+
+  pub fn get_alpha(&self) -> f32 {
+    self.alpha as f32
   }
 
   pub fn hex_value(&self) -> String {
-    self.hex.to_string()
+    self.hex().to_string()
   }
 
   pub fn rgb_value(&self) -> String {
-    self.rgb.to_string()
+    self.rgb().to_string()
   }
 
   pub fn cmyk_value(&self) -> String {
-    self.cmyk.to_string()
+    self.cmyk().to_string()
   }
 
   pub fn hsl_value(&self) -> String {
-    self.hsl.to_string()
+    self.hsl().to_string()
   }
 
   pub fn hsv_value(&self) -> String {
-    self.hsv.to_string()
+    self.hsv().to_string()
+  }
+
+  pub fn lab_value(&self) -> String {
+    self.to_lab().to_string()
+  }
+
+  pub fn lch_value(&self) -> String {
+    Lch::from_lab(&self.to_lab()).to_string()
+  }
+
+  pub fn oklab_value(&self) -> String {
+    self.to_oklab().to_string()
+  }
+
+  pub fn oklch_value(&self) -> String {
+    self.to_oklch().to_string()
+  }
+
+  pub fn opaque_color_value(&self) -> String {
+    self.rgb().to_opaque_color_string()
+  }
+
+  // t = [0,1], 0 gives this color and 1 the other one
+  pub fn mix(&self, other: &Color, t: f64, space: InterpolationSpace) -> Color {
+    let alpha = lerp(self.alpha, other.alpha, t);
+    let mix = |first, second| mix_components(first, self.alpha, second, other.alpha, t);
+
+    match space {
+      InterpolationSpace::Srgb => {
+        let [red, green, blue] = mix(self.srgb_values(), other.srgb_values());
+        Color::from_srgb_values(red, green, blue, alpha)
+      }
+
+      InterpolationSpace::LinearRgb => {
+        let linear = |color: &Color| color.srgb_values().map(srgb_to_linear);
+        let [red, green, blue] = mix(linear(self), linear(other)).map(linear_to_srgb);
+        Color::from_srgb_values(red, green, blue, alpha)
+      }
+
+      InterpolationSpace::Hsl(hue_interpolation) => {
+        let (first, second) = (self.hsl(), other.hsl());
+        let hue = hue_interpolation.interpolate(
+          Color::powerless_hue(first.get_hue(), first.get_saturation()),
+          Color::powerless_hue(second.get_hue(), second.get_saturation()),
+          t,
+        );
+        let [saturation, lightness] = mix_components(
+          [first.get_saturation(), first.get_lightness()],
+          self.alpha,
+          [second.get_saturation(), second.get_lightness()],
+          other.alpha,
+          t,
+        );
+
+        let hsl = HSL::from_values(hue, saturation, lightness, alpha);
+        Color::from_hsv_model(&HSV::from_hsl(&hsl))
+      }
+
+      InterpolationSpace::Hsv(hue_interpolation) => {
+        let (first, second) = (self.hsv(), other.hsv());
+        let hue = hue_interpolation.interpolate(
+          Color::powerless_hue(first.get_hue(), first.get_saturation()),
+          Color::powerless_hue(second.get_hue(), second.get_saturation()),
+          t,
+        );
+        let [saturation, value] = mix_components(
+          [first.get_saturation(), first.get_value()],
+          self.alpha,
+          [second.get_saturation(), second.get_value()],
+          other.alpha,
+          t,
+        );
+
+        Color::from_hsv_model(&HSV::from_values(hue, saturation, value, alpha))
+      }
+
+      InterpolationSpace::Lab => {
+        let lab_values = |lab: Lab| [lab.get_lightness(), lab.get_a(), lab.get_b()];
+        let [lightness, a, b] = mix(lab_values(self.to_lab()), lab_values(other.to_lab()));
+        Color::from_rgb_model(&RGB::from_lab(&Lab::from_values(lightness, a, b, alpha)))
+      }
+
+      InterpolationSpace::Oklab => {
+        let oklab_values = |oklab: Oklab| [oklab.get_lightness(), oklab.get_a(), oklab.get_b()];
+        let [lightness, a, b] = mix(
+          oklab_values(self.to_oklab()),
+          oklab_values(other.to_oklab()),
+        );
+        Color::from_rgb_model(&RGB::from_oklab(&Oklab::from_values(
+          lightness, a, b, alpha,
+        )))
+      }
+
+      InterpolationSpace::Oklch(hue_interpolation) => {
+        let (first, second) = (self.to_oklch(), other.to_oklch());
+        let hue = hue_interpolation.interpolate(
+          Color::powerless_hue(first.get_hue(), first.get_chroma()),
+          Color::powerless_hue(second.get_hue(), second.get_chroma()),
+          t,
+        );
+        let [lightness, chroma] = mix_components(
+          [first.get_lightness(), first.get_chroma()],
+          self.alpha,
+          [second.get_lightness(), second.get_chroma()],
+          other.alpha,
+          t,
+        );
+
+        Color::from_oklch_values(lightness, chroma, hue, alpha)
+      }
+    }
+  }
+
+  // the hue of an achromatic color is missing, the other color's hue is used instead
+  fn powerless_hue(hue: f64, chroma: f64) -> Option<f64> {
+    if chroma < ACHROMATIC_EPSILON {
+      None
+    } else {
+      Some(hue)
+    }
+  }
+
+  pub fn to_css_string(&self, format: CssFormat) -> String {
+    match format {
+      CssFormat::Hex => self.hex().to_color_string(),
+      CssFormat::Rgb => self.rgb().to_color_string(),
+      CssFormat::Hsl => self.hsl().to_color_string(),
+      CssFormat::Hwb => self.hsv().to_color_string(),
+      CssFormat::DeviceCmyk => self.cmyk().to_color_string(),
+      CssFormat::Oklab => self.to_oklab().to_color_string(),
+      CssFormat::Oklch => self.to_oklch().to_color_string(),
+    }
+  }
+}
+
+impl FromStr for Color {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Color, ParseColorError> {
+    Color::parse(value)
   }
 }
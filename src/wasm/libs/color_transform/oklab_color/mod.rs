@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::constants::{MAX_ALPHA, MAX_OKLAB_AB, MAX_OKLAB_L, MIN_OKLAB_AB, MIN_OKLAB_L};
+use crate::libs::color_transform::oklch_color::Oklch;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, format_decimal, parse_alpha, parse_component, parse_signed_component,
+  split_css_components, ColorComponent, ParseColorError,
+};
+use crate::libs::color_transform::rgb_color::RGB;
+use crate::libs::color_transform::xyz_color::{Adaptation, WhitePoint, Xyz};
+use crate::libs::css_color::color_space::xyz_d65_to_oklab;
+
+// lightness is kept in [0,1] and written as a percentage, like CSS oklab() does
+#[derive(Serialize, Deserialize)]
+pub struct Oklab {
+  lightness: f64,
+  a: f64,
+  b: f64,
+  alpha: f64,
+}
+
+impl Oklab {
+  // l = [0,1], a = [-0.4,0.4], b = [-0.4,0.4], alpha = [0,1]
+  pub fn from_values(l: f64, a: f64, b: f64, alpha: f64) -> Oklab {
+    Oklab {
+      lightness: l,
+      a,
+      b,
+      alpha,
+    }
+  }
+
+  pub fn from_rgb(rgb: &RGB) -> Oklab {
+    Oklab::from_xyz(&Xyz::from_rgb(rgb))
+  }
+
+  // other white points are adapted to D65 first
+  pub fn from_xyz(xyz: &Xyz) -> Oklab {
+    let xyz = xyz.adapt(WhitePoint::D65, Adaptation::Bradford);
+    let oklab = xyz_d65_to_oklab(xyz.get_values());
+
+    Oklab {
+      lightness: oklab[0],
+      a: oklab[1],
+      b: oklab[2],
+      alpha: xyz.get_alpha(),
+    }
+  }
+
+  pub fn from_oklch(oklch: &Oklch) -> Oklab {
+    let hue = oklch.get_hue().to_radians();
+
+    Oklab {
+      lightness: oklch.get_lightness(),
+      a: oklch.get_chroma() * hue.cos(),
+      b: oklch.get_chroma() * hue.sin(),
+      alpha: oklch.get_alpha(),
+    }
+  }
+
+  pub fn get_lightness(&self) -> f64 {
+    self.lightness
+  }
+
+  pub fn get_a(&self) -> f64 {
+    self.a
+  }
+
+  pub fn get_b(&self) -> f64 {
+    self.b
+  }
+
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
+  pub fn to_string(&self) -> String {
+    let value = format!(
+      "{}% {} {}",
+      format_decimal(self.lightness * 100.0, 2),
+      format_decimal(self.a, 4),
+      format_decimal(self.b, 4)
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{} / {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+
+  pub fn to_color_string(&self) -> String {
+    format!("oklab({})", self.to_string())
+  }
+}
+
+impl FromStr for Oklab {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Oklab, ParseColorError> {
+    let (parts, alpha) = split_css_components(value, 3)?;
+
+    let lightness = parse_component(
+      parts[0],
+      ColorComponent::Lightness,
+      "%",
+      MIN_OKLAB_L,
+      MAX_OKLAB_L,
+    )?;
+    let a = parse_signed_component(
+      parts[1],
+      ColorComponent::GreenRed,
+      "",
+      MIN_OKLAB_AB,
+      MAX_OKLAB_AB,
+    )?;
+    let b = parse_signed_component(
+      parts[2],
+      ColorComponent::BlueYellow,
+      "",
+      MIN_OKLAB_AB,
+      MAX_OKLAB_AB,
+    )?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(Oklab {
+      lightness: lightness / f64::from(MAX_OKLAB_L),
+      a,
+      b,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for Oklab {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<Oklab, ParseColorError> {
+    value.parse()
+  }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::constants::{
+  MAX_ALPHA, MAX_H, MAX_OKLAB_L, MAX_OKLCH_C, MIN_HSV, MIN_OKLAB_L, MIN_OKLCH_C,
+};
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, format_decimal, parse_alpha, parse_component, split_css_components, ColorComponent,
+  ParseColorError,
+};
+
+// cylindrical form of OKLab: lightness, chroma and hue
+#[derive(Serialize, Deserialize)]
+pub struct Oklch {
+  lightness: f64,
+  chroma: f64,
+  hue: f64,
+  alpha: f64,
+}
+
+impl Oklch {
+  // l = [0,1], c = [0,0.4], h = [0,360], a = [0,1]
+  pub fn from_values(l: f64, c: f64, h: f64, a: f64) -> Oklch {
+    Oklch {
+      lightness: l,
+      chroma: c,
+      hue: h,
+      alpha: a,
+    }
+  }
+
+  pub fn from_oklab(oklab: &Oklab) -> Oklch {
+    let chroma = oklab.get_a().hypot(oklab.get_b());
+    let hue = oklab.get_b().atan2(oklab.get_a()).to_degrees();
+
+    Oklch {
+      lightness: oklab.get_lightness(),
+      chroma,
+      hue: hue.rem_euclid(f64::from(MAX_H)),
+      alpha: oklab.get_alpha(),
+    }
+  }
+
+  pub fn get_lightness(&self) -> f64 {
+    self.lightness
+  }
+
+  pub fn get_chroma(&self) -> f64 {
+    self.chroma
+  }
+
+  pub fn get_hue(&self) -> f64 {
+    self.hue
+  }
+
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
+  pub fn to_string(&self) -> String {
+    let value = format!(
+      "{}% {} {}",
+      format_decimal(self.lightness * 100.0, 2),
+      format_decimal(self.chroma, 4),
+      format_decimal(self.hue, 2)
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{} / {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+
+  pub fn to_color_string(&self) -> String {
+    format!("oklch({})", self.to_string())
+  }
+}
+
+impl FromStr for Oklch {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Oklch, ParseColorError> {
+    let (parts, alpha) = split_css_components(value, 3)?;
+
+    let lightness = parse_component(
+      parts[0],
+      ColorComponent::Lightness,
+      "%",
+      MIN_OKLAB_L,
+      MAX_OKLAB_L,
+    )?;
+    let chroma = parse_component(
+      parts[1],
+      ColorComponent::Chroma,
+      "",
+      MIN_OKLCH_C,
+      MAX_OKLCH_C,
+    )?;
+    let hue = parse_component(parts[2], ColorComponent::Hue, "", MIN_HSV, MAX_H)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(Oklch {
+      lightness: lightness / f64::from(MAX_OKLAB_L),
+      chroma,
+      hue,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for Oklch {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<Oklch, ParseColorError> {
+    value.parse()
+  }
+}
@@ -0,0 +1,212 @@
+use std::error::Error;
+use std::fmt;
+
+use regex::Regex;
+
+use crate::constants::{MAX_ALPHA, MIN_ALPHA, NUMBER_REG_EXP, SIGNED_NUMBER_REG_EXP};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorComponent {
+  Hex,
+  Red,
+  Green,
+  Blue,
+  Cyan,
+  Magenta,
+  Yellow,
+  Black,
+  Hue,
+  Saturation,
+  Value,
+  Lightness,
+  Whiteness,
+  Blackness,
+  Chroma,
+  GreenRed,
+  BlueYellow,
+  X,
+  Y,
+  Z,
+  Alpha,
+}
+
+impl fmt::Display for ColorComponent {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let name = match self {
+      ColorComponent::Hex => "hex",
+      ColorComponent::Red => "red",
+      ColorComponent::Green => "green",
+      ColorComponent::Blue => "blue",
+      ColorComponent::Cyan => "cyan",
+      ColorComponent::Magenta => "magenta",
+      ColorComponent::Yellow => "yellow",
+      ColorComponent::Black => "black",
+      ColorComponent::Hue => "hue",
+      ColorComponent::Saturation => "saturation",
+      ColorComponent::Value => "value",
+      ColorComponent::Lightness => "lightness",
+      ColorComponent::Whiteness => "whiteness",
+      ColorComponent::Blackness => "blackness",
+      ColorComponent::Chroma => "chroma",
+      ColorComponent::GreenRed => "a",
+      ColorComponent::BlueYellow => "b",
+      ColorComponent::X => "x",
+      ColorComponent::Y => "y",
+      ColorComponent::Z => "z",
+      ColorComponent::Alpha => "alpha",
+    };
+
+    f.write_str(name)
+  }
+}
+
+/// Describes why a color string could not be turned into a color.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseColorError {
+  /// The string does not look like any supported color notation.
+  UnknownFormat(String),
+  /// The string has a wrong number of comma separated components.
+  ComponentCount { expected: usize, found: usize },
+  /// A component is not a number or lacks the expected unit.
+  Malformed {
+    component: ColorComponent,
+    value: String,
+  },
+  /// A component is a number but lies outside of the allowed range.
+  OutOfRange {
+    component: ColorComponent,
+    value: f64,
+  },
+}
+
+impl fmt::Display for ParseColorError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseColorError::UnknownFormat(value) => write!(f, "unknown color format: \"{}\"", value),
+      ParseColorError::ComponentCount { expected, found } => {
+        write!(f, "expected {} color components, found {}", expected, found)
+      }
+      ParseColorError::Malformed { component, value } => {
+        write!(f, "malformed {} component: \"{}\"", component, value)
+      }
+      ParseColorError::OutOfRange { component, value } => {
+        write!(f, "{} component is out of range: {}", component, value)
+      }
+    }
+  }
+}
+
+impl Error for ParseColorError {}
+
+// splits "a, b, c" or "a, b, c, alpha" into the color components and the optional opacity
+pub(crate) fn split_components(
+  value: &str,
+  expected: usize,
+) -> Result<(Vec<&str>, Option<&str>), ParseColorError> {
+  let mut parts: Vec<&str> = value.split(',').map(|part| part.trim()).collect();
+  let alpha = if parts.len() == expected + 1 {
+    parts.pop()
+  } else {
+    None
+  };
+
+  if parts.len() != expected {
+    return Err(ParseColorError::ComponentCount {
+      expected,
+      found: parts.len(),
+    });
+  }
+
+  Ok((parts, alpha))
+}
+
+// splits CSS like "a b c" or "a b c / alpha" into the color components and the optional opacity
+pub(crate) fn split_css_components(
+  value: &str,
+  expected: usize,
+) -> Result<(Vec<&str>, Option<&str>), ParseColorError> {
+  let mut halves = value.splitn(2, '/');
+  let parts: Vec<&str> = halves.next().unwrap_or("").split_whitespace().collect();
+  let alpha = halves.next().map(|alpha| alpha.trim());
+
+  if parts.len() != expected {
+    return Err(ParseColorError::ComponentCount {
+      expected,
+      found: parts.len(),
+    });
+  }
+
+  Ok((parts, alpha))
+}
+
+// reads a single component like "42", "42%" or "42°" and checks its range
+pub(crate) fn parse_component(
+  part: &str,
+  component: ColorComponent,
+  unit: &str,
+  min: f32,
+  max: f32,
+) -> Result<f64, ParseColorError> {
+  parse_number(part, component, unit, min, max, &NUMBER_REG_EXP)
+}
+
+// "200°" or "200" like in CSS
+pub(crate) fn parse_hue(part: &str, min: f32, max: f32) -> Result<f64, ParseColorError> {
+  let part = part.strip_suffix('°').unwrap_or(part);
+  parse_component(part, ColorComponent::Hue, "", min, max)
+}
+
+// only the a and b axes of Lab and OKLab may be written with a minus sign
+pub(crate) fn parse_signed_component(
+  part: &str,
+  component: ColorComponent,
+  unit: &str,
+  min: f32,
+  max: f32,
+) -> Result<f64, ParseColorError> {
+  parse_number(part, component, unit, min, max, &SIGNED_NUMBER_REG_EXP)
+}
+
+fn parse_number(
+  part: &str,
+  component: ColorComponent,
+  unit: &str,
+  min: f32,
+  max: f32,
+  reg_exp: &Regex,
+) -> Result<f64, ParseColorError> {
+  let malformed = || ParseColorError::Malformed {
+    component,
+    value: part.to_string(),
+  };
+
+  let number = part.strip_suffix(unit).ok_or_else(malformed)?;
+  if !reg_exp.is_match(number) {
+    return Err(malformed());
+  }
+
+  let value = number.parse::<f64>().map_err(|_| malformed())?;
+  if value < f64::from(min) || value > f64::from(max) {
+    return Err(ParseColorError::OutOfRange { component, value });
+  }
+
+  Ok(value)
+}
+
+pub(crate) fn parse_alpha(part: Option<&str>) -> Result<f64, ParseColorError> {
+  match part {
+    Some(part) => parse_component(part, ColorComponent::Alpha, "", MIN_ALPHA, MAX_ALPHA),
+    None => Ok(f64::from(MAX_ALPHA)),
+  }
+}
+
+// opacity is printed with two decimals at most, e.g. "0.5"
+pub(crate) fn format_alpha(alpha: f64) -> String {
+  format_decimal(alpha, 2)
+}
+
+// prints the given number of decimals at most, adding zero turns a rounded "-0" into "0"
+pub(crate) fn format_decimal(value: f64, places: i32) -> String {
+  let factor = 10_f64.powi(places);
+  format!("{}", (value * factor).round() / factor + 0.0)
+}
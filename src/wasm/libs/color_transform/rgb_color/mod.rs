@@ -1,94 +1,126 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
 use crate::libs::color_transform::cmyk_color::CMYK;
 use crate::libs::color_transform::hex_color::Hex;
 use crate::libs::color_transform::hsv_color::HSV;
+use crate::libs::color_transform::lab_color::Lab;
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::parse_error::{
+  format_alpha, parse_alpha, parse_component, split_components, ColorComponent, ParseColorError,
+};
+use crate::libs::color_transform::xyz_color::{Adaptation, WhitePoint, Xyz};
+use crate::libs::css_color::color_space::{linear_to_srgb, srgb_to_linear, xyz_d65_to_linear_srgb};
 
 use crate::constants::{
-  HEX_LONG_REG_EXP, HEX_SHORT_REG_EXP, MAX_CMYK, MAX_RGB, MAX_SVL, RGB_REG_EXP,
+  HEX_LONG_REG_EXP, HEX_SHORT_REG_EXP, MAX_ALPHA, MAX_CMYK, MAX_RGB, MAX_SVL, MIN_RGB,
 };
 
 #[derive(Serialize, Deserialize)]
 pub struct RGB {
-  red: f32,
-  green: f32,
-  blue: f32,
+  red: f64,
+  green: f64,
+  blue: f64,
+  alpha: f64,
 }
 
 impl RGB {
-  fn values_to_rgb(r: f32, g: f32, b: f32) -> RGB {
+  fn values_to_rgb(r: f64, g: f64, b: f64, a: f64) -> RGB {
+    let max = f64::from(MAX_RGB);
+
     RGB {
-      red: (r * MAX_RGB).round(),
-      green: (g * MAX_RGB).round(),
-      blue: (b * MAX_RGB).round(),
+      red: r * max,
+      green: g * max,
+      blue: b * max,
+      alpha: a,
     }
   }
 
-  pub fn new(value: String) -> RGB {
-    let mut red: f32 = 0.0;
-    let mut green: f32 = 0.0;
-    let mut blue: f32 = 0.0;
-
-    for cap in RGB_REG_EXP.captures_iter(&value) {
-      red = cap[1].parse::<f32>().unwrap();
-      green = cap[2].parse::<f32>().unwrap();
-      blue = cap[3].parse::<f32>().unwrap();
+  // r = [0,255], g = [0,255], b = [0,255], a = [0,1]
+  pub fn from_values(r: f64, g: f64, b: f64, a: f64) -> RGB {
+    RGB {
+      red: r,
+      green: g,
+      blue: b,
+      alpha: a,
     }
-
-    RGB { red, green, blue }
   }
 
   pub fn from_cmyk(cmyk: &CMYK) -> RGB {
-    let c_norm: f32 = cmyk.get_cyan() / MAX_CMYK;
-    let m_norm: f32 = cmyk.get_magenta() / MAX_CMYK;
-    let y_norm: f32 = cmyk.get_yellow() / MAX_CMYK;
-    let k_norm: f32 = cmyk.get_black() / MAX_CMYK;
+    let max = f64::from(MAX_CMYK);
+
+    let c_norm = cmyk.get_cyan() / max;
+    let m_norm = cmyk.get_magenta() / max;
+    let y_norm = cmyk.get_yellow() / max;
+    let k_norm = cmyk.get_black() / max;
 
     let red = (1.0 - c_norm) * (1.0 - k_norm);
     let green = (1.0 - m_norm) * (1.0 - k_norm);
     let blue = (1.0 - y_norm) * (1.0 - k_norm);
 
-    RGB {
-      red: (red * MAX_RGB).round(),
-      green: (green * MAX_RGB).round(),
-      blue: (blue * MAX_RGB).round(),
-    }
+    RGB::values_to_rgb(red, green, blue, cmyk.get_alpha())
   }
 
   pub fn from_hsv(hsv: &HSV) -> RGB {
-    let s_norm = hsv.get_saturation() / MAX_SVL;
-    let v_norm = hsv.get_value() / MAX_SVL;
+    let max = f64::from(MAX_SVL);
+
+    let s_norm = hsv.get_saturation() / max;
+    let v_norm = hsv.get_value() / max;
+    let a = hsv.get_alpha();
 
     if hsv.get_saturation() == 0.0 {
-      return RGB::values_to_rgb(v_norm, v_norm, v_norm);
+      return RGB::values_to_rgb(v_norm, v_norm, v_norm, a);
     }
 
-    let h_sector: f32 = hsv.get_hue() / 60.0; // sector 0 to 5
+    let h_sector = hsv.get_hue() / 60.0; // sector 0 to 5
     let i = h_sector.floor();
     let f = h_sector - i; // factorial part of h
-    let p: f32 = v_norm * (1.0 - s_norm);
-    let q: f32 = v_norm * (1.0 - s_norm * f);
-    let t: f32 = v_norm * (1.0 - s_norm * (1.0 - f));
+    let p = v_norm * (1.0 - s_norm);
+    let q = v_norm * (1.0 - s_norm * f);
+    let t = v_norm * (1.0 - s_norm * (1.0 - f));
 
     if i == 0.0 || i == 6.0 {
-      return RGB::values_to_rgb(v_norm, t, p);
+      return RGB::values_to_rgb(v_norm, t, p, a);
     } else if i == 1.0 {
-      return RGB::values_to_rgb(q, v_norm, p);
+      return RGB::values_to_rgb(q, v_norm, p, a);
     } else if i == 2.0 {
-      return RGB::values_to_rgb(p, v_norm, t);
+      return RGB::values_to_rgb(p, v_norm, t, a);
     } else if i == 3.0 {
-      return RGB::values_to_rgb(p, q, v_norm);
+      return RGB::values_to_rgb(p, q, v_norm, a);
     } else if i == 4.0 {
-      return RGB::values_to_rgb(t, p, v_norm);
+      return RGB::values_to_rgb(t, p, v_norm, a);
     } else {
-      return RGB::values_to_rgb(v_norm, p, q);
+      return RGB::values_to_rgb(v_norm, p, q, a);
     }
   }
 
+  // colors outside of the sRGB gamut are clipped
+  pub fn from_xyz(xyz: &Xyz) -> RGB {
+    let xyz = xyz.adapt(WhitePoint::D65, Adaptation::Bradford);
+    let linear = xyz_d65_to_linear_srgb(xyz.get_values());
+    let channel = |value: f64| linear_to_srgb(value).clamp(0.0, 1.0);
+
+    RGB::values_to_rgb(
+      channel(linear[0]),
+      channel(linear[1]),
+      channel(linear[2]),
+      xyz.get_alpha(),
+    )
+  }
+
+  pub fn from_lab(lab: &Lab) -> RGB {
+    RGB::from_xyz(&Xyz::from_lab(lab))
+  }
+
+  pub fn from_oklab(oklab: &Oklab) -> RGB {
+    RGB::from_xyz(&Xyz::from_oklab(oklab))
+  }
+
   pub fn from_hex(hex: &Hex) -> RGB {
     let mut hex_value = if HEX_SHORT_REG_EXP.is_match(&hex.get_value()) {
       HEX_SHORT_REG_EXP
-        .replace_all(&hex.get_value(), "$r$r$g$g$b$b")
+        .replace_all(&hex.get_value(), "$r$r$g$g$b$b$a$a")
         .to_string()
     } else {
       hex.get_value().to_string()
@@ -98,37 +130,112 @@ impl RGB {
     let mut red = 0;
     let mut green = 0;
     let mut blue = 0;
+    let mut alpha = MAX_RGB as i32;
 
     for cap in HEX_LONG_REG_EXP.captures_iter(&hex_value) {
       red = i32::from_str_radix(&cap[1], 16).unwrap();
       green = i32::from_str_radix(&cap[2], 16).unwrap();
       blue = i32::from_str_radix(&cap[3], 16).unwrap();
+
+      if let Some(a) = cap.get(4) {
+        alpha = i32::from_str_radix(a.as_str(), 16).unwrap();
+      }
     }
 
     return RGB {
-      red: red as f32,
-      green: green as f32,
-      blue: blue as f32,
+      red: f64::from(red),
+      green: f64::from(green),
+      blue: f64::from(blue),
+      alpha: f64::from(alpha) / f64::from(MAX_RGB),
     };
   }
 
-  pub fn get_red(&self) -> f32 {
+  // relative luminance as defined by WCAG 2.x, 0 for black and 1 for white
+  pub fn relative_luminance(&self) -> f64 {
+    let max = f64::from(MAX_RGB);
+
+    0.2126 * srgb_to_linear(self.red / max)
+      + 0.7152 * srgb_to_linear(self.green / max)
+      + 0.0722 * srgb_to_linear(self.blue / max)
+  }
+
+  pub fn get_red(&self) -> f64 {
     self.red
   }
 
-  pub fn get_green(&self) -> f32 {
+  pub fn get_green(&self) -> f64 {
     self.green
   }
 
-  pub fn get_blue(&self) -> f32 {
+  pub fn get_blue(&self) -> f64 {
     self.blue
   }
 
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
   pub fn to_string(&self) -> String {
-    format!("{}, {}, {}", self.red, self.green, self.blue)
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!(
+        "{}, {}, {}, {}",
+        self.red.round(),
+        self.green.round(),
+        self.blue.round(),
+        format_alpha(self.alpha)
+      );
+    }
+
+    self.to_opaque_string()
   }
 
   pub fn to_color_string(&self) -> String {
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("rgba({})", self.to_string());
+    }
+
     format!("rgb({})", self.to_string())
   }
+
+  // the same color without transparency, e.g. for the end of the opacity gradient
+  pub fn to_opaque_color_string(&self) -> String {
+    format!("rgb({})", self.to_opaque_string())
+  }
+
+  fn to_opaque_string(&self) -> String {
+    format!(
+      "{}, {}, {}",
+      self.red.round(),
+      self.green.round(),
+      self.blue.round()
+    )
+  }
+}
+
+impl FromStr for RGB {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<RGB, ParseColorError> {
+    let (parts, alpha) = split_components(value, 3)?;
+
+    let red = parse_component(parts[0], ColorComponent::Red, "", MIN_RGB, MAX_RGB)?;
+    let green = parse_component(parts[1], ColorComponent::Green, "", MIN_RGB, MAX_RGB)?;
+    let blue = parse_component(parts[2], ColorComponent::Blue, "", MIN_RGB, MAX_RGB)?;
+    let alpha = parse_alpha(alpha)?;
+
+    Ok(RGB {
+      red,
+      green,
+      blue,
+      alpha,
+    })
+  }
+}
+
+impl TryFrom<&str> for RGB {
+  type Error = ParseColorError;
+
+  fn try_from(value: &str) -> Result<RGB, ParseColorError> {
+    value.parse()
+  }
 }
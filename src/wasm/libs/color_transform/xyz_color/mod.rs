@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{MAX_ALPHA, MAX_RGB};
+use crate::libs::color_transform::lab_color::Lab;
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::parse_error::{format_alpha, format_decimal};
+use crate::libs::color_transform::rgb_color::RGB;
+use crate::libs::css_color::color_space::{
+  invert, lab_to_xyz, linear_srgb_to_xyz_d65, multiply, multiply_matrices, oklab_to_xyz_d65,
+  srgb_to_linear, Matrix, D50_WHITE, D65_WHITE,
+};
+
+const BRADFORD: Matrix = [
+  [0.8951, 0.2664, -0.1614],
+  [-0.7502, 1.7135, 0.0367],
+  [0.0389, -0.0685, 1.0296],
+];
+
+// Hunt-Pointer-Estevez cone response, normalized to D65
+const VON_KRIES: Matrix = [
+  [0.40024, 0.7076, -0.08081],
+  [-0.2263, 1.16532, 0.0457],
+  [0.0, 0.0, 0.91822],
+];
+
+const XYZ_SCALING: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// CIE standard illuminants used as reference white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WhitePoint {
+  A,
+  B,
+  C,
+  D50,
+  D55,
+  D65,
+  D75,
+  E,
+  F2,
+  F7,
+  F11,
+}
+
+impl WhitePoint {
+  // tristimulus values of the white, normalized to Y = 1
+  pub fn xyz(&self) -> [f64; 3] {
+    // D50 and D65 use the same rounding as the sRGB and CSS matrices
+    let (x, y) = match self {
+      WhitePoint::D50 => return D50_WHITE,
+      WhitePoint::D65 => return D65_WHITE,
+      WhitePoint::A => (0.44757, 0.40745),
+      WhitePoint::B => (0.34842, 0.35161),
+      WhitePoint::C => (0.31006, 0.31616),
+      WhitePoint::D55 => (0.33242, 0.34743),
+      WhitePoint::D75 => (0.29902, 0.31485),
+      WhitePoint::E => (1.0 / 3.0, 1.0 / 3.0),
+      WhitePoint::F2 => (0.37208, 0.37529),
+      WhitePoint::F7 => (0.31292, 0.32933),
+      WhitePoint::F11 => (0.38052, 0.37713),
+    };
+
+    [x / y, 1.0, (1.0 - x - y) / y]
+  }
+}
+
+/// Chromatic adaptation transforms which move a color between white points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Adaptation {
+  Bradford,
+  VonKries,
+  XyzScaling,
+}
+
+impl Adaptation {
+  fn cone_response(&self) -> Matrix {
+    match self {
+      Adaptation::Bradford => BRADFORD,
+      Adaptation::VonKries => VON_KRIES,
+      Adaptation::XyzScaling => XYZ_SCALING,
+    }
+  }
+
+  // M⁻¹ · diag(destination / source) · M, where M maps XYZ to the cone response
+  pub fn matrix(&self, source: WhitePoint, destination: WhitePoint) -> Matrix {
+    let cone_response = self.cone_response();
+    let source = multiply(&cone_response, source.xyz());
+    let destination = multiply(&cone_response, destination.xyz());
+
+    let mut scale = [[0.0; 3]; 3];
+    for (index, row) in scale.iter_mut().enumerate() {
+      row[index] = destination[index] / source[index];
+    }
+
+    multiply_matrices(
+      &invert(&cone_response),
+      &multiply_matrices(&scale, &cone_response),
+    )
+  }
+}
+
+/// CIE XYZ with Y = 1 for the reference white, the hub between RGB, Lab and OKLab.
+///
+/// HSV, HSL and CMYK are derived from RGB directly: they are device models without a
+/// colorimetric definition of their own, so going through XYZ would not make them more accurate.
+#[derive(Serialize, Deserialize)]
+pub struct Xyz {
+  x: f64,
+  y: f64,
+  z: f64,
+  white: WhitePoint,
+  alpha: f64,
+}
+
+impl Xyz {
+  pub fn from_values(x: f64, y: f64, z: f64, white: WhitePoint, alpha: f64) -> Xyz {
+    Xyz {
+      x,
+      y,
+      z,
+      white,
+      alpha,
+    }
+  }
+
+  // sRGB is defined relative to D65
+  pub fn from_rgb(rgb: &RGB) -> Xyz {
+    let max = f64::from(MAX_RGB);
+
+    let xyz = linear_srgb_to_xyz_d65([
+      srgb_to_linear(rgb.get_red() / max),
+      srgb_to_linear(rgb.get_green() / max),
+      srgb_to_linear(rgb.get_blue() / max),
+    ]);
+
+    Xyz::from_values(xyz[0], xyz[1], xyz[2], WhitePoint::D65, rgb.get_alpha())
+  }
+
+  pub fn from_lab(lab: &Lab) -> Xyz {
+    let xyz = lab_to_xyz(lab.get_lightness(), lab.get_a(), lab.get_b(), D65_WHITE);
+    Xyz::from_values(xyz[0], xyz[1], xyz[2], WhitePoint::D65, lab.get_alpha())
+  }
+
+  pub fn from_oklab(oklab: &Oklab) -> Xyz {
+    let xyz = oklab_to_xyz_d65(oklab.get_lightness(), oklab.get_a(), oklab.get_b());
+    Xyz::from_values(xyz[0], xyz[1], xyz[2], WhitePoint::D65, oklab.get_alpha())
+  }
+
+  pub fn adapt(&self, white: WhitePoint, method: Adaptation) -> Xyz {
+    if white == self.white {
+      return Xyz::from_values(self.x, self.y, self.z, white, self.alpha);
+    }
+
+    let xyz = multiply(&method.matrix(self.white, white), self.get_values());
+    Xyz::from_values(xyz[0], xyz[1], xyz[2], white, self.alpha)
+  }
+
+  pub fn get_x(&self) -> f64 {
+    self.x
+  }
+
+  pub fn get_y(&self) -> f64 {
+    self.y
+  }
+
+  pub fn get_z(&self) -> f64 {
+    self.z
+  }
+
+  pub fn get_values(&self) -> [f64; 3] {
+    [self.x, self.y, self.z]
+  }
+
+  pub fn get_white(&self) -> WhitePoint {
+    self.white
+  }
+
+  pub fn get_alpha(&self) -> f64 {
+    self.alpha
+  }
+
+  // printed on the usual 0 to 100 scale
+  pub fn to_string(&self) -> String {
+    let value = format!(
+      "{}, {}, {}",
+      format_decimal(self.x * 100.0, 2),
+      format_decimal(self.y * 100.0, 2),
+      format_decimal(self.z * 100.0, 2)
+    );
+
+    if self.alpha < f64::from(MAX_ALPHA) {
+      return format!("{}, {}", value, format_alpha(self.alpha));
+    }
+
+    value
+  }
+}
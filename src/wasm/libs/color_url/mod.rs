@@ -0,0 +1,113 @@
+use crate::libs::color_transform::parse_error::{format_alpha, format_decimal};
+use crate::libs::color_transform::Color;
+
+// "?color=%23ff8800" accepts anything Color::parse understands
+const QUERY_KEY: &str = "color";
+
+fn decode_component(value: &str) -> String {
+  let bytes = value.as_bytes();
+  let mut decoded = Vec::with_capacity(bytes.len());
+  let mut index = 0;
+
+  while index < bytes.len() {
+    let hex = bytes
+      .get(index + 1..index + 3)
+      .and_then(|hex| std::str::from_utf8(hex).ok())
+      .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+    match (bytes[index], hex) {
+      (b'%', Some(byte)) => {
+        decoded.push(byte);
+        index += 3;
+      }
+      (b'+', _) => {
+        decoded.push(b' ');
+        index += 1;
+      }
+      (byte, _) => {
+        decoded.push(byte);
+        index += 1;
+      }
+    }
+  }
+
+  String::from_utf8_lossy(&decoded).to_string()
+}
+
+// "key=value" pairs separated by "&", the leading "?" or "#" is skipped
+fn params(value: &str) -> impl Iterator<Item = (String, String)> + '_ {
+  value
+    .trim_start_matches(['?', '#'])
+    .split('&')
+    .filter_map(|pair| {
+      let (key, value) = pair.split_once('=')?;
+
+      Some((
+        decode_component(key).to_ascii_lowercase(),
+        decode_component(value),
+      ))
+    })
+}
+
+// values may be written with or without the units, "200,50,50" is the same as "200°, 50%, 50%"
+fn with_units(value: &str, units: [&str; 3]) -> String {
+  let parts: Vec<String> = value
+    .split(',')
+    .enumerate()
+    .map(|(index, part)| {
+      let part = part.trim().trim_end_matches(['°', '%']);
+      match units.get(index) {
+        Some(unit) => format!("{}{}", part, unit),
+        None => part.to_string(),
+      }
+    })
+    .collect();
+
+  parts.join(", ")
+}
+
+fn parse_hash_value(key: &str, value: &str) -> Option<Color> {
+  let color = match key {
+    "hex" => Color::from_hex(format!("#{}", value.trim_start_matches('#'))),
+    "rgb" => Color::from_rgb(value.to_string()),
+    "hsl" => Color::from_hsl(with_units(value, ["°", "%", "%"])),
+    "hsv" => Color::from_hsv(with_units(value, ["°", "%", "%"])),
+    "cmyk" => Color::from_cmyk(with_units(value, ["%", "%", "%"])),
+    QUERY_KEY => Color::parse(value),
+    _ => return None,
+  };
+
+  color.ok()
+}
+
+/// Finds the color of a shared link: "#hex=ff8800", "#hsl=200,50,50" and the other models
+/// in the hash win over "?color=..." in the query, since the hash is kept in sync later on.
+pub fn parse_url_color(search: &str, hash: &str) -> Option<Color> {
+  params(hash)
+    .find_map(|(key, value)| parse_hash_value(&key, &value))
+    .or_else(|| {
+      params(search)
+        .filter(|(key, _)| key == QUERY_KEY)
+        .find_map(|(_, value)| Color::parse(&value).ok())
+    })
+}
+
+// e.g. "#hex=ff8800", grays and black keep their hue in "#hsv=200,0,50.2" instead
+pub fn url_hash(color: &Color) -> String {
+  let [hue, saturation, value, alpha] = color.hsva_values();
+  if saturation > 0.0 && value > 0.0 {
+    return format!("#hex={}", color.hex_value().trim_start_matches('#'));
+  }
+
+  // one decimal of the value is enough to get the same hex back
+  let mut values = vec![
+    format_decimal(hue, 1),
+    format_decimal(saturation, 1),
+    format_decimal(value, 1),
+  ];
+  if alpha < 1.0 {
+    values.push(format_alpha(alpha));
+  }
+
+  format!("#hsv={}", values.join(","))
+}
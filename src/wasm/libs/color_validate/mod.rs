@@ -1,8 +1,21 @@
+use regex::Match;
+
 use crate::constants::{
-  CMYK_REG_EXP, HEX_LONG_REG_EXP, HEX_SHORT_REG_EXP, HSV_REG_EXP, MAX_CMYK, MAX_H, MAX_RGB,
-  MAX_SVL, MIN_CMYK, MIN_HSV, MIN_RGB, RGB_REG_EXP,
+  CMYK_REG_EXP, HEX_LONG_REG_EXP, HEX_SHORT_REG_EXP, HSL_REG_EXP, HSV_REG_EXP, LAB_REG_EXP,
+  LCH_REG_EXP, MAX_ALPHA, MAX_CMYK, MAX_H, MAX_LAB_AB, MAX_LAB_L, MAX_LCH_C, MAX_OKLAB_AB,
+  MAX_OKLAB_L, MAX_OKLCH_C, MAX_RGB, MAX_SVL, MIN_ALPHA, MIN_CMYK, MIN_HSV, MIN_LAB_AB, MIN_LAB_L,
+  MIN_LCH_C, MIN_OKLAB_AB, MIN_OKLAB_L, MIN_OKLCH_C, MIN_RGB, OKLAB_REG_EXP, OKLCH_REG_EXP,
+  RGB_REG_EXP,
 };
 
+// alpha is optional, a missing one means an opaque color
+fn is_valid_alpha(alpha: Option<Match>) -> bool {
+  match alpha {
+    Some(alpha) => (MIN_ALPHA..=MAX_ALPHA).contains(&alpha.as_str().parse::<f32>().unwrap()),
+    None => true,
+  }
+}
+
 pub fn is_valid_hex(hex: &str) -> bool {
   HEX_SHORT_REG_EXP.is_match(hex) || HEX_LONG_REG_EXP.is_match(hex)
 }
@@ -15,11 +28,13 @@ pub fn is_valid_rgb(rgb: &str) -> bool {
   let mut red: f32 = -1.0;
   let mut green: f32 = -1.0;
   let mut blue: f32 = -1.0;
+  let mut alpha = true;
 
   for cap in RGB_REG_EXP.captures_iter(&rgb) {
     red = cap[1].parse::<f32>().unwrap();
     green = cap[2].parse::<f32>().unwrap();
     blue = cap[3].parse::<f32>().unwrap();
+    alpha = is_valid_alpha(cap.get(4));
   }
 
   return MIN_RGB <= red
@@ -27,7 +42,8 @@ pub fn is_valid_rgb(rgb: &str) -> bool {
     && MIN_RGB <= green
     && green <= MAX_RGB
     && MIN_RGB <= blue
-    && blue <= MAX_RGB;
+    && blue <= MAX_RGB
+    && alpha;
 }
 
 pub fn is_valid_cmyk(cmyk: &str) -> bool {
@@ -39,12 +55,14 @@ pub fn is_valid_cmyk(cmyk: &str) -> bool {
   let mut magenta: f32 = -1.0;
   let mut yellow: f32 = -1.0;
   let mut key: f32 = -1.0;
+  let mut alpha = true;
 
   for cap in CMYK_REG_EXP.captures_iter(&cmyk) {
     cyan = cap[1].parse::<f32>().unwrap();
     magenta = cap[2].parse::<f32>().unwrap();
     yellow = cap[3].parse::<f32>().unwrap();
     key = cap[4].parse::<f32>().unwrap();
+    alpha = is_valid_alpha(cap.get(5));
   }
 
   return MIN_CMYK <= cyan
@@ -54,7 +72,8 @@ pub fn is_valid_cmyk(cmyk: &str) -> bool {
     && MIN_CMYK <= yellow
     && yellow <= MAX_CMYK
     && MIN_CMYK <= key
-    && key <= MAX_CMYK;
+    && key <= MAX_CMYK
+    && alpha;
 }
 
 pub fn is_valid_hsv(hsv: &str) -> bool {
@@ -65,11 +84,13 @@ pub fn is_valid_hsv(hsv: &str) -> bool {
   let mut hue: f32 = -1.0;
   let mut saturation: f32 = -1.0;
   let mut value: f32 = -1.0;
+  let mut alpha = true;
 
   for cap in HSV_REG_EXP.captures_iter(&hsv) {
     hue = cap[1].parse::<f32>().unwrap();
     saturation = cap[2].parse::<f32>().unwrap();
     value = cap[3].parse::<f32>().unwrap();
+    alpha = is_valid_alpha(cap.get(4));
   }
 
   return MIN_HSV <= hue
@@ -77,5 +98,86 @@ pub fn is_valid_hsv(hsv: &str) -> bool {
     && MIN_HSV <= saturation
     && saturation <= MAX_SVL
     && MIN_HSV <= value
-    && value <= MAX_SVL;
+    && value <= MAX_SVL
+    && alpha;
+}
+
+pub fn is_valid_hsl(hsl: &str) -> bool {
+  let cap = match HSL_REG_EXP.captures(hsl) {
+    Some(cap) => cap,
+    None => return false,
+  };
+
+  let hue = cap[1].parse::<f32>().unwrap();
+  let saturation = cap[2].parse::<f32>().unwrap();
+  let lightness = cap[3].parse::<f32>().unwrap();
+
+  (MIN_HSV..=MAX_H).contains(&hue)
+    && (MIN_HSV..=MAX_SVL).contains(&saturation)
+    && (MIN_HSV..=MAX_SVL).contains(&lightness)
+    && is_valid_alpha(cap.get(4))
+}
+
+pub fn is_valid_lab(lab: &str) -> bool {
+  let cap = match LAB_REG_EXP.captures(lab) {
+    Some(cap) => cap,
+    None => return false,
+  };
+
+  let lightness = cap[1].parse::<f32>().unwrap();
+  let a = cap[2].parse::<f32>().unwrap();
+  let b = cap[3].parse::<f32>().unwrap();
+
+  (MIN_LAB_L..=MAX_LAB_L).contains(&lightness)
+    && (MIN_LAB_AB..=MAX_LAB_AB).contains(&a)
+    && (MIN_LAB_AB..=MAX_LAB_AB).contains(&b)
+    && is_valid_alpha(cap.get(4))
+}
+
+pub fn is_valid_lch(lch: &str) -> bool {
+  let cap = match LCH_REG_EXP.captures(lch) {
+    Some(cap) => cap,
+    None => return false,
+  };
+
+  let lightness = cap[1].parse::<f32>().unwrap();
+  let chroma = cap[2].parse::<f32>().unwrap();
+  let hue = cap[3].parse::<f32>().unwrap();
+
+  (MIN_LAB_L..=MAX_LAB_L).contains(&lightness)
+    && (MIN_LCH_C..=MAX_LCH_C).contains(&chroma)
+    && (MIN_HSV..=MAX_H).contains(&hue)
+    && is_valid_alpha(cap.get(4))
+}
+
+pub fn is_valid_oklab(oklab: &str) -> bool {
+  let cap = match OKLAB_REG_EXP.captures(oklab) {
+    Some(cap) => cap,
+    None => return false,
+  };
+
+  let lightness = cap[1].parse::<f32>().unwrap();
+  let a = cap[2].parse::<f32>().unwrap();
+  let b = cap[3].parse::<f32>().unwrap();
+
+  (MIN_OKLAB_L..=MAX_OKLAB_L).contains(&lightness)
+    && (MIN_OKLAB_AB..=MAX_OKLAB_AB).contains(&a)
+    && (MIN_OKLAB_AB..=MAX_OKLAB_AB).contains(&b)
+    && is_valid_alpha(cap.get(4))
+}
+
+pub fn is_valid_oklch(oklch: &str) -> bool {
+  let cap = match OKLCH_REG_EXP.captures(oklch) {
+    Some(cap) => cap,
+    None => return false,
+  };
+
+  let lightness = cap[1].parse::<f32>().unwrap();
+  let chroma = cap[2].parse::<f32>().unwrap();
+  let hue = cap[3].parse::<f32>().unwrap();
+
+  (MIN_OKLAB_L..=MAX_OKLAB_L).contains(&lightness)
+    && (MIN_OKLCH_C..=MAX_OKLCH_C).contains(&chroma)
+    && (MIN_HSV..=MAX_H).contains(&hue)
+    && is_valid_alpha(cap.get(4))
 }
@@ -0,0 +1,45 @@
+use crate::libs::color_transform::parse_error::format_decimal;
+use crate::libs::color_transform::Color;
+
+// minimal WCAG 2.x ratios for normal and large (18pt or 14pt bold) text
+const AA_NORMAL: f64 = 4.5;
+const AA_LARGE: f64 = 3.0;
+const AAA_NORMAL: f64 = 7.0;
+const AAA_LARGE: f64 = 4.5;
+
+/// Which WCAG 2.x success criteria a contrast ratio satisfies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WcagCompliance {
+  pub aa_normal: bool,
+  pub aa_large: bool,
+  pub aaa_normal: bool,
+  pub aaa_large: bool,
+}
+
+impl WcagCompliance {
+  pub fn from_ratio(ratio: f64) -> WcagCompliance {
+    WcagCompliance {
+      aa_normal: ratio >= AA_NORMAL,
+      aa_large: ratio >= AA_LARGE,
+      aaa_normal: ratio >= AAA_NORMAL,
+      aaa_large: ratio >= AAA_LARGE,
+    }
+  }
+}
+
+// from 1 for equal luminance up to 21 for black on white, the order of the colors doesn't matter
+pub fn contrast_ratio(first: &Color, second: &Color) -> f64 {
+  let first = first.relative_luminance();
+  let second = second.relative_luminance();
+
+  (first.max(second) + 0.05) / (first.min(second) + 0.05)
+}
+
+pub fn compliance(first: &Color, second: &Color) -> WcagCompliance {
+  WcagCompliance::from_ratio(contrast_ratio(first, second))
+}
+
+// e.g. "4.54:1"
+pub fn format_ratio(ratio: f64) -> String {
+  format!("{}:1", format_decimal(ratio, 2))
+}
@@ -0,0 +1,326 @@
+// Matrices and transfer functions are the ones published with the CSS Color 4 sample code
+
+pub type Matrix = [[f64; 3]; 3];
+
+const LINEAR_SRGB_TO_XYZ_D65: Matrix = [
+  [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+  [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+  [0.01933081871559182, 0.11919477979462598, 0.9505321522496606],
+];
+
+const XYZ_D65_TO_LINEAR_SRGB: Matrix = [
+  [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+  [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+  [
+    0.05563007969699366,
+    -0.20397695888897652,
+    1.0569715142428786,
+  ],
+];
+
+const LINEAR_DISPLAY_P3_TO_XYZ_D65: Matrix = [
+  [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+  [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+  [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const LINEAR_A98_RGB_TO_XYZ_D65: Matrix = [
+  [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+  [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+  [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+const LINEAR_PROPHOTO_RGB_TO_XYZ_D50: Matrix = [
+  [0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+  [
+    0.2880711282292934,
+    0.7118432178101014,
+    0.00008565396060525902,
+  ],
+  [0.0, 0.0, 0.8251046025104601],
+];
+
+const LINEAR_REC2020_TO_XYZ_D65: Matrix = [
+  [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+  [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+  [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+// Bradford chromatic adaptation
+const XYZ_D50_TO_XYZ_D65: Matrix = [
+  [
+    0.9554734527042182,
+    -0.023098536874261423,
+    0.0632593086610217,
+  ],
+  [
+    -0.028369706963208136,
+    1.0099954580058226,
+    0.021041398966943008,
+  ],
+  [
+    0.012314001688319899,
+    -0.020507696433477912,
+    1.3303659366080753,
+  ],
+];
+
+// OKLab from XYZ relative to D65, through the cone response (LMS)
+const XYZ_D65_TO_LMS: Matrix = [
+  [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+  [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+  [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LMS_TO_XYZ_D65: Matrix = [
+  [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+  [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+  [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+  [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+  [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+  [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+  [1.0, 0.3963377773761749, 0.2158037573099136],
+  [1.0, -0.1055613458156586, -0.0638541728258133],
+  [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+pub const D50_WHITE: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+pub const D65_WHITE: [f64; 3] = [0.3127 / 0.329, 1.0, (1.0 - 0.3127 - 0.329) / 0.329];
+
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+/// Predefined RGB and XYZ spaces accepted by the CSS `color()` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredefinedSpace {
+  Srgb,
+  SrgbLinear,
+  DisplayP3,
+  A98Rgb,
+  ProphotoRgb,
+  Rec2020,
+  XyzD50,
+  XyzD65,
+}
+
+impl PredefinedSpace {
+  pub fn from_name(name: &str) -> Option<PredefinedSpace> {
+    match name {
+      "srgb" => Some(PredefinedSpace::Srgb),
+      "srgb-linear" => Some(PredefinedSpace::SrgbLinear),
+      "display-p3" => Some(PredefinedSpace::DisplayP3),
+      "a98-rgb" => Some(PredefinedSpace::A98Rgb),
+      "prophoto-rgb" => Some(PredefinedSpace::ProphotoRgb),
+      "rec2020" => Some(PredefinedSpace::Rec2020),
+      "xyz-d50" => Some(PredefinedSpace::XyzD50),
+      "xyz" | "xyz-d65" => Some(PredefinedSpace::XyzD65),
+      _ => None,
+    }
+  }
+
+  pub fn is_xyz(&self) -> bool {
+    *self == PredefinedSpace::XyzD50 || *self == PredefinedSpace::XyzD65
+  }
+
+  pub fn to_linear_srgb(&self, channels: [f64; 3]) -> [f64; 3] {
+    match self {
+      PredefinedSpace::Srgb => map(channels, srgb_to_linear),
+      PredefinedSpace::SrgbLinear => channels,
+      PredefinedSpace::DisplayP3 => xyz_d65_to_linear_srgb(multiply(
+        &LINEAR_DISPLAY_P3_TO_XYZ_D65,
+        map(channels, srgb_to_linear),
+      )),
+      PredefinedSpace::A98Rgb => xyz_d65_to_linear_srgb(multiply(
+        &LINEAR_A98_RGB_TO_XYZ_D65,
+        map(channels, a98_rgb_to_linear),
+      )),
+      PredefinedSpace::ProphotoRgb => xyz_d50_to_linear_srgb(multiply(
+        &LINEAR_PROPHOTO_RGB_TO_XYZ_D50,
+        map(channels, prophoto_rgb_to_linear),
+      )),
+      PredefinedSpace::Rec2020 => xyz_d65_to_linear_srgb(multiply(
+        &LINEAR_REC2020_TO_XYZ_D65,
+        map(channels, rec2020_to_linear),
+      )),
+      PredefinedSpace::XyzD50 => xyz_d50_to_linear_srgb(channels),
+      PredefinedSpace::XyzD65 => xyz_d65_to_linear_srgb(channels),
+    }
+  }
+}
+
+pub fn multiply(matrix: &Matrix, vector: [f64; 3]) -> [f64; 3] {
+  let mut result = [0.0; 3];
+  for (row, value) in matrix.iter().zip(result.iter_mut()) {
+    *value = row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+  }
+
+  result
+}
+
+pub fn multiply_matrices(left: &Matrix, right: &Matrix) -> Matrix {
+  let mut result = [[0.0; 3]; 3];
+  for (row, result_row) in left.iter().zip(result.iter_mut()) {
+    for (column, value) in result_row.iter_mut().enumerate() {
+      *value = row[0] * right[0][column] + row[1] * right[1][column] + row[2] * right[2][column];
+    }
+  }
+
+  result
+}
+
+// inverts a non singular matrix with the adjugate method
+pub fn invert(matrix: &Matrix) -> Matrix {
+  let m = matrix;
+  let cofactor =
+    |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+  let adjugate = [
+    [
+      cofactor(1, 2, 1, 2),
+      -cofactor(0, 2, 1, 2),
+      cofactor(0, 1, 1, 2),
+    ],
+    [
+      -cofactor(1, 2, 0, 2),
+      cofactor(0, 2, 0, 2),
+      -cofactor(0, 1, 0, 2),
+    ],
+    [
+      cofactor(1, 2, 0, 1),
+      -cofactor(0, 2, 0, 1),
+      cofactor(0, 1, 0, 1),
+    ],
+  ];
+  let determinant = m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+  let mut result = adjugate;
+  for value in result.iter_mut().flat_map(|row| row.iter_mut()) {
+    *value /= determinant;
+  }
+
+  result
+}
+
+fn map(channels: [f64; 3], transfer: fn(f64) -> f64) -> [f64; 3] {
+  [
+    transfer(channels[0]),
+    transfer(channels[1]),
+    transfer(channels[2]),
+  ]
+}
+
+pub fn srgb_to_linear(value: f64) -> f64 {
+  let abs = value.abs();
+  if abs <= 0.04045 {
+    return value / 12.92;
+  }
+
+  value.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+}
+
+pub fn linear_to_srgb(value: f64) -> f64 {
+  let abs = value.abs();
+  if abs <= 0.0031308 {
+    return value * 12.92;
+  }
+
+  value.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+}
+
+fn a98_rgb_to_linear(value: f64) -> f64 {
+  value.signum() * value.abs().powf(563.0 / 256.0)
+}
+
+fn prophoto_rgb_to_linear(value: f64) -> f64 {
+  let abs = value.abs();
+  if abs <= 16.0 / 512.0 {
+    return value / 16.0;
+  }
+
+  value.signum() * abs.powf(1.8)
+}
+
+fn rec2020_to_linear(value: f64) -> f64 {
+  let alpha = 1.09929682680944;
+  let beta = 0.018053968510807;
+
+  let abs = value.abs();
+  if abs < beta * 4.5 {
+    return value / 4.5;
+  }
+
+  value.signum() * ((abs + alpha - 1.0) / alpha).powf(1.0 / 0.45)
+}
+
+pub fn linear_srgb_to_xyz_d65(rgb: [f64; 3]) -> [f64; 3] {
+  multiply(&LINEAR_SRGB_TO_XYZ_D65, rgb)
+}
+
+pub fn xyz_d65_to_linear_srgb(xyz: [f64; 3]) -> [f64; 3] {
+  multiply(&XYZ_D65_TO_LINEAR_SRGB, xyz)
+}
+
+pub fn xyz_d50_to_linear_srgb(xyz: [f64; 3]) -> [f64; 3] {
+  xyz_d65_to_linear_srgb(multiply(&XYZ_D50_TO_XYZ_D65, xyz))
+}
+
+// CSS lab() is relative to the D50 white point
+pub fn lab_to_xyz_d50(lightness: f64, a: f64, b: f64) -> [f64; 3] {
+  lab_to_xyz(lightness, a, b, D50_WHITE)
+}
+
+pub fn lab_to_xyz(lightness: f64, a: f64, b: f64, white: [f64; 3]) -> [f64; 3] {
+  let f1 = (lightness + 16.0) / 116.0;
+  let f0 = a / 500.0 + f1;
+  let f2 = f1 - b / 200.0;
+
+  let x = if f0.powi(3) > LAB_EPSILON {
+    f0.powi(3)
+  } else {
+    (116.0 * f0 - 16.0) / LAB_KAPPA
+  };
+  let y = if lightness > LAB_KAPPA * LAB_EPSILON {
+    f1.powi(3)
+  } else {
+    lightness / LAB_KAPPA
+  };
+  let z = if f2.powi(3) > LAB_EPSILON {
+    f2.powi(3)
+  } else {
+    (116.0 * f2 - 16.0) / LAB_KAPPA
+  };
+
+  [x * white[0], y * white[1], z * white[2]]
+}
+
+pub fn xyz_to_lab(xyz: [f64; 3], white: [f64; 3]) -> [f64; 3] {
+  let f = |value: f64| {
+    if value > LAB_EPSILON {
+      value.cbrt()
+    } else {
+      (LAB_KAPPA * value + 16.0) / 116.0
+    }
+  };
+
+  let fx = f(xyz[0] / white[0]);
+  let fy = f(xyz[1] / white[1]);
+  let fz = f(xyz[2] / white[2]);
+
+  [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+pub fn xyz_d65_to_oklab(xyz: [f64; 3]) -> [f64; 3] {
+  let lms = multiply(&XYZ_D65_TO_LMS, xyz).map(f64::cbrt);
+  multiply(&LMS_TO_OKLAB, lms)
+}
+
+pub fn oklab_to_xyz_d65(lightness: f64, a: f64, b: f64) -> [f64; 3] {
+  let lms = multiply(&OKLAB_TO_LMS, [lightness, a, b]).map(|value| value.powi(3));
+  multiply(&LMS_TO_XYZ_D65, lms)
+}
@@ -0,0 +1,391 @@
+use crate::constants::{CSS_NUMBER_REG_EXP, MAX_RGB, NOTATION_REG_EXP};
+use crate::libs::color_transform::oklab_color::Oklab;
+use crate::libs::color_transform::parse_error::{ColorComponent, ParseColorError};
+use crate::libs::color_transform::xyz_color::Xyz;
+use crate::libs::color_transform::Color;
+use crate::libs::named_color;
+
+pub mod color_space;
+
+use color_space::{
+  lab_to_xyz_d50, linear_to_srgb, xyz_d50_to_linear_srgb, xyz_d65_to_linear_srgb, PredefinedSpace,
+};
+
+// reference values of 100% for the components which allow percentages
+const LAB_AB_PERCENT: f64 = 125.0;
+const LCH_CHROMA_PERCENT: f64 = 150.0;
+const OKLAB_AB_PERCENT: f64 = 0.4;
+
+/// CSS notations a color can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssFormat {
+  Hex,
+  Rgb,
+  Hsl,
+  Hwb,
+  DeviceCmyk,
+  Oklab,
+  Oklch,
+}
+
+struct Arguments<'a> {
+  channels: Vec<&'a str>,
+  alpha: Option<&'a str>,
+}
+
+/// Parses any color a browser accepts in CSS Color Level 4 syntax: hex notation, named colors,
+/// `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`, `color()`
+/// and `device-cmyk()`. Colors outside of sRGB are clipped to its gamut.
+pub fn parse(value: &str) -> Result<Color, ParseColorError> {
+  let value = value.trim().to_ascii_lowercase();
+
+  if value.starts_with('#') {
+    return parse_hex(&value);
+  }
+
+  if let Some(color) = named_color::find(&value) {
+    return Ok(color);
+  }
+
+  let cap = match NOTATION_REG_EXP.captures(&value) {
+    Some(cap) => cap,
+    None => return Err(ParseColorError::UnknownFormat(value)),
+  };
+
+  let args = &cap[2];
+  let (rgb, alpha) = match &cap[1] {
+    "rgb" | "rgba" => parse_rgb(split_arguments(args, 3)?)?,
+    "hsl" | "hsla" => parse_hsl(split_arguments(args, 3)?)?,
+    "hwb" => parse_hwb(split_arguments(args, 3)?)?,
+    "lab" => parse_lab(split_arguments(args, 3)?)?,
+    "lch" => parse_lch(split_arguments(args, 3)?)?,
+    "oklab" => parse_oklab(split_arguments(args, 3)?)?,
+    "oklch" => parse_oklch(split_arguments(args, 3)?)?,
+    "color" => parse_color_function(split_arguments(args, 4)?)?,
+    "device-cmyk" => parse_device_cmyk(split_arguments(args, 4)?)?,
+    _ => return Err(ParseColorError::UnknownFormat(value.to_string())),
+  };
+
+  Ok(to_color(rgb, alpha))
+}
+
+fn parse_hex(value: &str) -> Result<Color, ParseColorError> {
+  let digits = &value[1..];
+  if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+    return Err(ParseColorError::Malformed {
+      component: ColorComponent::Hex,
+      value: value.to_string(),
+    });
+  }
+
+  let channel_len = match digits.len() {
+    3 | 4 => 1,
+    6 | 8 => 2,
+    _ => {
+      return Err(ParseColorError::Malformed {
+        component: ColorComponent::Hex,
+        value: value.to_string(),
+      })
+    }
+  };
+
+  // #rgba and #rrggbbaa carry the opacity in the last channel
+  let mut rgba = [1.0; 4];
+  let count = digits.len() / channel_len;
+  for (index, channel) in rgba.iter_mut().take(count).enumerate() {
+    let part = &digits[index * channel_len..(index + 1) * channel_len];
+    let number = u8::from_str_radix(part, 16).unwrap();
+
+    *channel = if channel_len == 1 {
+      f64::from(number * 17)
+    } else {
+      f64::from(number)
+    } / f64::from(MAX_RGB);
+  }
+
+  Ok(to_color([rgba[0], rgba[1], rgba[2]], rgba[3]))
+}
+
+// splits both the legacy "a, b, c, alpha" and the modern "a b c / alpha" syntax
+fn split_arguments(args: &str, count: usize) -> Result<Arguments<'_>, ParseColorError> {
+  if args.contains(',') {
+    let mut channels: Vec<&str> = args.split(',').map(|part| part.trim()).collect();
+    let alpha = if channels.len() == count + 1 {
+      channels.pop()
+    } else {
+      None
+    };
+
+    if channels.len() != count {
+      return Err(ParseColorError::ComponentCount {
+        expected: count,
+        found: channels.len(),
+      });
+    }
+
+    return Ok(Arguments { channels, alpha });
+  }
+
+  let mut parts = args.splitn(2, '/');
+  let channels: Vec<&str> = parts.next().unwrap_or("").split_whitespace().collect();
+  let alpha = parts.next().map(|alpha| alpha.trim());
+
+  if channels.len() != count {
+    return Err(ParseColorError::ComponentCount {
+      expected: count,
+      found: channels.len(),
+    });
+  }
+
+  Ok(Arguments { channels, alpha })
+}
+
+fn read_number(part: &str, component: ColorComponent) -> Result<f64, ParseColorError> {
+  if !CSS_NUMBER_REG_EXP.is_match(part) {
+    return Err(ParseColorError::Malformed {
+      component,
+      value: part.to_string(),
+    });
+  }
+
+  Ok(part.parse::<f64>().unwrap())
+}
+
+// reads a number or a percentage, where 100% equals to `reference`
+fn read_channel(
+  part: &str,
+  component: ColorComponent,
+  reference: f64,
+) -> Result<f64, ParseColorError> {
+  if part == "none" {
+    return Ok(0.0);
+  }
+
+  match part.strip_suffix('%') {
+    Some(percentage) => Ok(read_number(percentage, component)? / 100.0 * reference),
+    None => read_number(part, component),
+  }
+}
+
+// reads a percentage or a number and returns it as a fraction of 100%
+fn read_percentage(part: &str, component: ColorComponent) -> Result<f64, ParseColorError> {
+  let value = read_channel(part, component, 100.0)?;
+  Ok((value / 100.0).clamp(0.0, 1.0))
+}
+
+// reads an angle and returns it in degrees
+fn read_hue(part: &str) -> Result<f64, ParseColorError> {
+  if part == "none" {
+    return Ok(0.0);
+  }
+
+  let units = [
+    ("deg", 1.0),
+    ("grad", 0.9),
+    ("rad", 180.0 / std::f64::consts::PI),
+    ("turn", 360.0),
+  ];
+
+  for (unit, factor) in units.iter() {
+    if let Some(number) = part.strip_suffix(unit) {
+      return Ok(read_number(number, ColorComponent::Hue)? * factor);
+    }
+  }
+
+  read_number(part, ColorComponent::Hue)
+}
+
+fn read_alpha(alpha: Option<&str>) -> Result<f64, ParseColorError> {
+  match alpha {
+    Some(part) => Ok(read_channel(part, ColorComponent::Alpha, 1.0)?.clamp(0.0, 1.0)),
+    None => Ok(1.0),
+  }
+}
+
+fn parse_rgb(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let components = [
+    ColorComponent::Red,
+    ColorComponent::Green,
+    ColorComponent::Blue,
+  ];
+
+  let mut rgb = [0.0; 3];
+  for (index, channel) in rgb.iter_mut().enumerate() {
+    let max = f64::from(MAX_RGB);
+    *channel = read_channel(args.channels[index], components[index], max)? / max;
+  }
+
+  let alpha = read_alpha(args.alpha)?;
+  Ok((rgb, alpha))
+}
+
+fn hsl_to_srgb(hue: f64, saturation: f64, lightness: f64) -> [f64; 3] {
+  let hue = hue.rem_euclid(360.0);
+
+  let channel = |n: f64| {
+    let k = (n + hue / 30.0) % 12.0;
+    let a = saturation * lightness.min(1.0 - lightness);
+    lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+  };
+
+  [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+fn parse_hsl(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let hue = read_hue(args.channels[0])?;
+  let saturation = read_percentage(args.channels[1], ColorComponent::Saturation)?;
+  let lightness = read_percentage(args.channels[2], ColorComponent::Lightness)?;
+
+  let alpha = read_alpha(args.alpha)?;
+  Ok((hsl_to_srgb(hue, saturation, lightness), alpha))
+}
+
+fn parse_hwb(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let hue = read_hue(args.channels[0])?;
+  let whiteness = read_percentage(args.channels[1], ColorComponent::Whiteness)?;
+  let blackness = read_percentage(args.channels[2], ColorComponent::Blackness)?;
+
+  let alpha = read_alpha(args.alpha)?;
+
+  if whiteness + blackness >= 1.0 {
+    let gray = whiteness / (whiteness + blackness);
+    return Ok(([gray, gray, gray], alpha));
+  }
+
+  let mut rgb = hsl_to_srgb(hue, 1.0, 0.5);
+  for channel in rgb.iter_mut() {
+    *channel = *channel * (1.0 - whiteness - blackness) + whiteness;
+  }
+
+  Ok((rgb, alpha))
+}
+
+fn parse_lab(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let lightness = read_channel(args.channels[0], ColorComponent::Lightness, 100.0)?;
+  let a = read_channel(args.channels[1], ColorComponent::GreenRed, LAB_AB_PERCENT)?;
+  let b = read_channel(args.channels[2], ColorComponent::BlueYellow, LAB_AB_PERCENT)?;
+
+  let alpha = read_alpha(args.alpha)?;
+
+  let xyz = lab_to_xyz_d50(lightness.clamp(0.0, 100.0), a, b);
+  Ok((linear_to_gamma(xyz_d50_to_linear_srgb(xyz)), alpha))
+}
+
+fn parse_lch(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let lightness = read_channel(args.channels[0], ColorComponent::Lightness, 100.0)?;
+  let chroma = read_channel(args.channels[1], ColorComponent::Chroma, LCH_CHROMA_PERCENT)?;
+  let hue = read_hue(args.channels[2])?.to_radians();
+
+  let alpha = read_alpha(args.alpha)?;
+
+  let chroma = chroma.max(0.0);
+  let xyz = lab_to_xyz_d50(
+    lightness.clamp(0.0, 100.0),
+    chroma * hue.cos(),
+    chroma * hue.sin(),
+  );
+  Ok((linear_to_gamma(xyz_d50_to_linear_srgb(xyz)), alpha))
+}
+
+// through the XYZ hub like every other OKLab conversion
+fn oklab_to_linear_srgb(lightness: f64, a: f64, b: f64) -> [f64; 3] {
+  let xyz = Xyz::from_oklab(&Oklab::from_values(lightness, a, b, 1.0));
+  xyz_d65_to_linear_srgb(xyz.get_values())
+}
+
+fn parse_oklab(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let lightness = read_channel(args.channels[0], ColorComponent::Lightness, 1.0)?;
+  let a = read_channel(args.channels[1], ColorComponent::GreenRed, OKLAB_AB_PERCENT)?;
+  let b = read_channel(
+    args.channels[2],
+    ColorComponent::BlueYellow,
+    OKLAB_AB_PERCENT,
+  )?;
+
+  let alpha = read_alpha(args.alpha)?;
+
+  let rgb = oklab_to_linear_srgb(lightness.clamp(0.0, 1.0), a, b);
+  Ok((linear_to_gamma(rgb), alpha))
+}
+
+fn parse_oklch(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let lightness = read_channel(args.channels[0], ColorComponent::Lightness, 1.0)?;
+  let chroma = read_channel(args.channels[1], ColorComponent::Chroma, OKLAB_AB_PERCENT)?;
+  let hue = read_hue(args.channels[2])?.to_radians();
+
+  let alpha = read_alpha(args.alpha)?;
+
+  let chroma = chroma.max(0.0);
+  let rgb = oklab_to_linear_srgb(
+    lightness.clamp(0.0, 1.0),
+    chroma * hue.cos(),
+    chroma * hue.sin(),
+  );
+  Ok((linear_to_gamma(rgb), alpha))
+}
+
+// the first argument of color() is the name of the color space
+fn parse_color_function(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let space = match PredefinedSpace::from_name(args.channels[0]) {
+    Some(space) => space,
+    None => return Err(ParseColorError::UnknownFormat(args.channels[0].to_string())),
+  };
+
+  let components = if space.is_xyz() {
+    [ColorComponent::X, ColorComponent::Y, ColorComponent::Z]
+  } else {
+    [
+      ColorComponent::Red,
+      ColorComponent::Green,
+      ColorComponent::Blue,
+    ]
+  };
+
+  let mut channels = [0.0; 3];
+  for (index, channel) in channels.iter_mut().enumerate() {
+    *channel = read_channel(args.channels[index + 1], components[index], 1.0)?;
+  }
+
+  let alpha = read_alpha(args.alpha)?;
+  Ok((linear_to_gamma(space.to_linear_srgb(channels)), alpha))
+}
+
+fn parse_device_cmyk(args: Arguments) -> Result<([f64; 3], f64), ParseColorError> {
+  let components = [
+    ColorComponent::Cyan,
+    ColorComponent::Magenta,
+    ColorComponent::Yellow,
+    ColorComponent::Black,
+  ];
+
+  let mut cmyk = [0.0; 4];
+  for (index, channel) in cmyk.iter_mut().enumerate() {
+    *channel = read_channel(args.channels[index], components[index], 1.0)?.clamp(0.0, 1.0);
+  }
+
+  let alpha = read_alpha(args.alpha)?;
+
+  let black = 1.0 - cmyk[3];
+  let rgb = [
+    (1.0 - cmyk[0]) * black,
+    (1.0 - cmyk[1]) * black,
+    (1.0 - cmyk[2]) * black,
+  ];
+
+  Ok((rgb, alpha))
+}
+
+fn linear_to_gamma(rgb: [f64; 3]) -> [f64; 3] {
+  [
+    linear_to_srgb(rgb[0]),
+    linear_to_srgb(rgb[1]),
+    linear_to_srgb(rgb[2]),
+  ]
+}
+
+// clips a gamma encoded sRGB triple to the gamut and builds the color from it
+fn to_color(rgb: [f64; 3], alpha: f64) -> Color {
+  let channel = |value: f64| value.clamp(0.0, 1.0);
+
+  Color::from_srgb_values(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]), alpha)
+}
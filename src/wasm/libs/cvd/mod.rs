@@ -0,0 +1,333 @@
+use serde::{Deserialize, Serialize};
+
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::color_space::{linear_to_srgb, multiply, srgb_to_linear, Matrix};
+
+/// Cone type which is missing or shifted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Deficiency {
+  // long wavelength (red) cones
+  Protan,
+  // medium wavelength (green) cones
+  Deutan,
+  // short wavelength (blue) cones
+  Tritan,
+}
+
+pub const DEFICIENCIES: [Deficiency; 3] =
+  [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+
+/// Simulation model, both work on linear sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CvdModel {
+  // Machado, Oliveira and Fernandes 2009, has its own matrices for anomalous trichromacy
+  Machado,
+  // Brettel, Viénot and Mollon 1997, projects onto two half-planes and is more accurate for tritanopia
+  Brettel,
+}
+
+pub const CVD_MODELS: [CvdModel; 2] = [CvdModel::Machado, CvdModel::Brettel];
+
+/// Deficiency with its severity, 1 is dichromacy (protanopia, deuteranopia, tritanopia) and
+/// anything below is anomalous trichromacy (protanomaly, deuteranomaly, tritanomaly).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Simulation {
+  pub deficiency: Deficiency,
+  pub severity: f64,
+  pub model: CvdModel,
+}
+
+impl Simulation {
+  pub fn new(deficiency: Deficiency, severity: f64, model: CvdModel) -> Simulation {
+    Simulation {
+      deficiency,
+      severity,
+      model,
+    }
+  }
+
+  pub fn apply(&self, color: &Color) -> Color {
+    simulate(color, self.deficiency, self.severity, self.model)
+  }
+}
+
+const IDENTITY: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+// Machado matrices for severities 0.1 to 1.0, severity 0 is the identity
+const MACHADO_PROTAN: [Matrix; 10] = [
+  [
+    [0.856167, 0.182038, -0.038205],
+    [0.029342, 0.955115, 0.015544],
+    [-0.002880, -0.001563, 1.004443],
+  ],
+  [
+    [0.734766, 0.334872, -0.069637],
+    [0.051840, 0.919198, 0.028963],
+    [-0.004928, -0.004209, 1.009137],
+  ],
+  [
+    [0.630323, 0.465641, -0.095964],
+    [0.069181, 0.890046, 0.040773],
+    [-0.006308, -0.007724, 1.014032],
+  ],
+  [
+    [0.539009, 0.579343, -0.118352],
+    [0.082546, 0.866121, 0.051332],
+    [-0.007136, -0.011959, 1.019095],
+  ],
+  [
+    [0.458064, 0.679578, -0.137642],
+    [0.092785, 0.846313, 0.060902],
+    [-0.007494, -0.016807, 1.024301],
+  ],
+  [
+    [0.385450, 0.769005, -0.154455],
+    [0.100526, 0.829802, 0.069673],
+    [-0.007442, -0.022190, 1.029632],
+  ],
+  [
+    [0.319627, 0.849633, -0.169261],
+    [0.106241, 0.815969, 0.077790],
+    [-0.007025, -0.028051, 1.035076],
+  ],
+  [
+    [0.259411, 0.923008, -0.182420],
+    [0.110296, 0.804340, 0.085364],
+    [-0.006276, -0.034346, 1.040622],
+  ],
+  [
+    [0.203876, 0.990338, -0.194214],
+    [0.112975, 0.794542, 0.092483],
+    [-0.005222, -0.041043, 1.046265],
+  ],
+  [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+  ],
+];
+
+const MACHADO_DEUTAN: [Matrix; 10] = [
+  [
+    [0.866435, 0.177704, -0.044139],
+    [0.049567, 0.939063, 0.011370],
+    [-0.003453, 0.007233, 0.996220],
+  ],
+  [
+    [0.760729, 0.319078, -0.079807],
+    [0.090568, 0.889315, 0.020117],
+    [-0.006027, 0.013325, 0.992702],
+  ],
+  [
+    [0.675425, 0.433850, -0.109275],
+    [0.125303, 0.847755, 0.026942],
+    [-0.007950, 0.018572, 0.989378],
+  ],
+  [
+    [0.605511, 0.528560, -0.134071],
+    [0.155318, 0.812366, 0.032316],
+    [-0.009376, 0.023176, 0.986200],
+  ],
+  [
+    [0.547494, 0.607765, -0.155259],
+    [0.181692, 0.781742, 0.036566],
+    [-0.010410, 0.027275, 0.983136],
+  ],
+  [
+    [0.498864, 0.674741, -0.173604],
+    [0.205199, 0.754872, 0.039929],
+    [-0.011131, 0.030969, 0.980162],
+  ],
+  [
+    [0.457771, 0.731899, -0.189670],
+    [0.226409, 0.731012, 0.042579],
+    [-0.011595, 0.034333, 0.977261],
+  ],
+  [
+    [0.422823, 0.781057, -0.203881],
+    [0.245752, 0.709602, 0.044646],
+    [-0.011843, 0.037423, 0.974421],
+  ],
+  [
+    [0.392952, 0.823610, -0.216562],
+    [0.263559, 0.690210, 0.046232],
+    [-0.011910, 0.040281, 0.971630],
+  ],
+  [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+  ],
+];
+
+const MACHADO_TRITAN: [Matrix; 10] = [
+  [
+    [0.926670, 0.092514, -0.019184],
+    [0.021191, 0.964503, 0.014306],
+    [0.008437, 0.054813, 0.936750],
+  ],
+  [
+    [0.895720, 0.133330, -0.029050],
+    [0.029997, 0.945400, 0.024603],
+    [0.013027, 0.104707, 0.882266],
+  ],
+  [
+    [0.905871, 0.127791, -0.033662],
+    [0.026856, 0.941251, 0.031893],
+    [0.013410, 0.148296, 0.838294],
+  ],
+  [
+    [0.948035, 0.089490, -0.037526],
+    [0.014364, 0.946792, 0.038844],
+    [0.010853, 0.193991, 0.795156],
+  ],
+  [
+    [1.017277, 0.027029, -0.044306],
+    [-0.006113, 0.958479, 0.047634],
+    [0.006379, 0.248708, 0.744913],
+  ],
+  [
+    [1.104996, -0.046633, -0.058363],
+    [-0.032137, 0.971635, 0.060503],
+    [0.001336, 0.317922, 0.680742],
+  ],
+  [
+    [1.193214, -0.109812, -0.083402],
+    [-0.058496, 0.979410, 0.079086],
+    [-0.002346, 0.403492, 0.598854],
+  ],
+  [
+    [1.257728, -0.139648, -0.118081],
+    [-0.078003, 0.975409, 0.102594],
+    [-0.003316, 0.501214, 0.502102],
+  ],
+  [
+    [1.278864, -0.125333, -0.153531],
+    [-0.084748, 0.957674, 0.127074],
+    [-0.000989, 0.601151, 0.399838],
+  ],
+  [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+  ],
+];
+
+// Brettel projections folded into linear sRGB with the Viénot LMS matrix, the first one is used
+// on the positive side of the separation plane
+struct BrettelParams {
+  first: Matrix,
+  second: Matrix,
+  normal: [f64; 3],
+}
+
+const BRETTEL_PROTAN: BrettelParams = BrettelParams {
+  first: [
+    [0.14510, 1.20165, -0.34675],
+    [0.10447, 0.85316, 0.04237],
+    [0.00429, -0.00603, 1.00174],
+  ],
+  second: [
+    [0.14115, 1.16782, -0.30897],
+    [0.10495, 0.85730, 0.03776],
+    [0.00431, -0.00586, 1.00155],
+  ],
+  normal: [0.00048, 0.00416, -0.00464],
+};
+
+const BRETTEL_DEUTAN: BrettelParams = BrettelParams {
+  first: [
+    [0.36198, 0.86755, -0.22953],
+    [0.26099, 0.64512, 0.09389],
+    [-0.01975, 0.02686, 0.99289],
+  ],
+  second: [
+    [0.37009, 0.88540, -0.25549],
+    [0.25767, 0.63782, 0.10451],
+    [-0.01950, 0.02741, 0.99209],
+  ],
+  normal: [-0.00293, -0.00645, 0.00938],
+};
+
+const BRETTEL_TRITAN: BrettelParams = BrettelParams {
+  first: [
+    [1.01354, 0.14268, -0.15622],
+    [-0.01181, 0.87561, 0.13619],
+    [0.07707, 0.81208, 0.11085],
+  ],
+  second: [
+    [0.93337, 0.19999, -0.13336],
+    [0.05809, 0.82565, 0.11626],
+    [-0.37923, 1.13825, 0.24098],
+  ],
+  normal: [0.03960, -0.02831, -0.01129],
+};
+
+fn lerp(from: [f64; 3], to: [f64; 3], t: f64) -> [f64; 3] {
+  [0, 1, 2].map(|index| from[index] + (to[index] - from[index]) * t)
+}
+
+// severities between the tabulated ones interpolate the neighbouring matrices
+fn machado(linear: [f64; 3], deficiency: Deficiency, severity: f64) -> [f64; 3] {
+  let matrices = match deficiency {
+    Deficiency::Protan => &MACHADO_PROTAN,
+    Deficiency::Deutan => &MACHADO_DEUTAN,
+    Deficiency::Tritan => &MACHADO_TRITAN,
+  };
+
+  let position = severity * 10.0;
+  let index = (position.floor() as usize).min(9);
+  let lower = if index == 0 {
+    &IDENTITY
+  } else {
+    &matrices[index - 1]
+  };
+
+  lerp(
+    multiply(lower, linear),
+    multiply(&matrices[index], linear),
+    position - index as f64,
+  )
+}
+
+// the dichromat color is blended with the original one for anomalous trichromacy
+fn brettel(linear: [f64; 3], deficiency: Deficiency, severity: f64) -> [f64; 3] {
+  let params = match deficiency {
+    Deficiency::Protan => &BRETTEL_PROTAN,
+    Deficiency::Deutan => &BRETTEL_DEUTAN,
+    Deficiency::Tritan => &BRETTEL_TRITAN,
+  };
+
+  let side =
+    linear[0] * params.normal[0] + linear[1] * params.normal[1] + linear[2] * params.normal[2];
+  let matrix = if side >= 0.0 {
+    &params.first
+  } else {
+    &params.second
+  };
+
+  lerp(linear, multiply(matrix, linear), severity)
+}
+
+// severity = [0,1], the alpha of the color is kept
+pub fn simulate(color: &Color, deficiency: Deficiency, severity: f64, model: CvdModel) -> Color {
+  let severity = severity.clamp(0.0, 1.0);
+  let linear = color.srgb_values().map(srgb_to_linear);
+
+  let simulated = match model {
+    CvdModel::Machado => machado(linear, deficiency, severity),
+    CvdModel::Brettel => brettel(linear, deficiency, severity),
+  };
+  let [red, green, blue] = simulated.map(|channel| linear_to_srgb(channel.clamp(0.0, 1.0)));
+
+  let [.., alpha] = color.hsva_values();
+  Color::from_srgb_values(red, green, blue, alpha)
+}
+
+// the color itself when no simulation is active
+pub fn seen_as(color: &Color, simulation: Option<Simulation>) -> Color {
+  match simulation {
+    Some(simulation) => simulation.apply(color),
+    None => color.clone(),
+  }
+}
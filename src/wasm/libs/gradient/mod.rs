@@ -0,0 +1,220 @@
+use crate::libs::color_transform::interpolation::{HueInterpolation, InterpolationSpace};
+use crate::libs::color_transform::parse_error::format_decimal;
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::CssFormat;
+
+// CSS can't interpolate in HSV, so that many intermediate stops are exported per segment instead
+const BAKED_STEPS: usize = 8;
+
+/// CSS gradient functions a gradient can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientShape {
+  Linear,
+  Radial,
+  Conic,
+}
+
+#[derive(Clone)]
+pub struct GradientStop {
+  pub color: Color,
+  // [0,1] along the gradient line
+  pub position: f64,
+}
+
+/// Color stops kept sorted by position, there are always at least two of them.
+#[derive(Clone)]
+pub struct Gradient {
+  stops: Vec<GradientStop>,
+  space: InterpolationSpace,
+  angle: f64,
+}
+
+impl Gradient {
+  pub fn new(first: Color, second: Color) -> Gradient {
+    Gradient {
+      stops: vec![
+        GradientStop {
+          color: first,
+          position: 0.0,
+        },
+        GradientStop {
+          color: second,
+          position: 1.0,
+        },
+      ],
+      space: InterpolationSpace::Oklab,
+      angle: 90.0,
+    }
+  }
+
+  pub fn get_stops(&self) -> &[GradientStop] {
+    &self.stops
+  }
+
+  pub fn get_space(&self) -> InterpolationSpace {
+    self.space
+  }
+
+  pub fn set_space(&mut self, space: InterpolationSpace) {
+    self.space = space;
+  }
+
+  // in degrees, used by linear and conic gradients
+  pub fn get_angle(&self) -> f64 {
+    self.angle
+  }
+
+  pub fn set_angle(&mut self, angle: f64) {
+    self.angle = angle;
+  }
+
+  // the new stop gets the color the gradient already has there, returns its index
+  pub fn add_stop(&mut self, position: f64) -> usize {
+    let position = position.clamp(0.0, 1.0);
+    let color = self.color_at(position);
+
+    self.insert_stop(GradientStop { color, position })
+  }
+
+  pub fn remove_stop(&mut self, index: usize) -> bool {
+    if self.stops.len() <= 2 || index >= self.stops.len() {
+      return false;
+    }
+
+    self.stops.remove(index);
+    true
+  }
+
+  // returns the index of the stop after the stops are sorted again
+  pub fn move_stop(&mut self, index: usize, position: f64) -> Option<usize> {
+    if index >= self.stops.len() {
+      return None;
+    }
+
+    let mut stop = self.stops.remove(index);
+    stop.position = position.clamp(0.0, 1.0);
+
+    Some(self.insert_stop(stop))
+  }
+
+  pub fn set_stop_color(&mut self, index: usize, color: Color) {
+    if let Some(stop) = self.stops.get_mut(index) {
+      stop.color = color;
+    }
+  }
+
+  fn insert_stop(&mut self, stop: GradientStop) -> usize {
+    let index = self
+      .stops
+      .iter()
+      .position(|other| other.position > stop.position)
+      .unwrap_or(self.stops.len());
+
+    self.stops.insert(index, stop);
+    index
+  }
+
+  pub fn color_at(&self, position: f64) -> Color {
+    let first = &self.stops[0];
+    if position <= first.position {
+      return first.color.clone();
+    }
+
+    for pair in self.stops.windows(2) {
+      let (start, end) = (&pair[0], &pair[1]);
+
+      if position <= end.position {
+        let length = end.position - start.position;
+        if length == 0.0 {
+          return end.color.clone();
+        }
+
+        let t = (position - start.position) / length;
+        return start.color.mix(&end.color, t, self.space);
+      }
+    }
+
+    self.stops[self.stops.len() - 1].color.clone()
+  }
+
+  // e.g. "linear-gradient(90deg in oklab, #ff0000 0%, #0000ff 100%)"
+  pub fn to_css(&self, shape: GradientShape) -> String {
+    let method = Gradient::interpolation_method(self.space);
+    let angle = format_decimal(self.angle, 2);
+
+    let prelude = match shape {
+      GradientShape::Linear => format!("{}deg", angle),
+      GradientShape::Radial => String::from("circle"),
+      GradientShape::Conic => format!("from {}deg", angle),
+    };
+
+    let prelude = match method {
+      Some(method) => format!("{} in {}", prelude, method),
+      None => prelude,
+    };
+
+    let function = match shape {
+      GradientShape::Linear => "linear-gradient",
+      GradientShape::Radial => "radial-gradient",
+      GradientShape::Conic => "conic-gradient",
+    };
+
+    let stops: Vec<String> = self
+      .css_stops()
+      .iter()
+      .map(|stop| {
+        format!(
+          "{} {}%",
+          stop.color.to_css_string(CssFormat::Hex),
+          format_decimal(stop.position * 100.0, 2)
+        )
+      })
+      .collect();
+
+    format!("{}({}, {})", function, prelude, stops.join(", "))
+  }
+
+  fn css_stops(&self) -> Vec<GradientStop> {
+    if Gradient::interpolation_method(self.space).is_some() {
+      return self.stops.clone();
+    }
+
+    let mut stops = vec![self.stops[0].clone()];
+    for pair in self.stops.windows(2) {
+      let (start, end) = (&pair[0], &pair[1]);
+
+      for step in 1..=BAKED_STEPS {
+        let t = step as f64 / BAKED_STEPS as f64;
+
+        stops.push(GradientStop {
+          color: start.color.mix(&end.color, t, self.space),
+          position: start.position + (end.position - start.position) * t,
+        });
+      }
+    }
+
+    stops
+  }
+
+  // the <color-interpolation-method> of CSS Images 4, None for spaces CSS doesn't know
+  fn interpolation_method(space: InterpolationSpace) -> Option<String> {
+    match space {
+      InterpolationSpace::Srgb => Some(String::from("srgb")),
+      InterpolationSpace::LinearRgb => Some(String::from("srgb-linear")),
+      InterpolationSpace::Hsl(hue) => Some(format!("hsl{}", Gradient::hue_method(hue))),
+      InterpolationSpace::Hsv(_) => None,
+      InterpolationSpace::Lab => Some(String::from("lab")),
+      InterpolationSpace::Oklab => Some(String::from("oklab")),
+      InterpolationSpace::Oklch(hue) => Some(format!("oklch{}", Gradient::hue_method(hue))),
+    }
+  }
+
+  fn hue_method(hue: HueInterpolation) -> &'static str {
+    match hue {
+      HueInterpolation::Shorter => "",
+      HueInterpolation::Longer => " longer hue",
+      HueInterpolation::Increasing => " increasing hue",
+      HueInterpolation::Decreasing => " decreasing hue",
+    }
+  }
+}
@@ -0,0 +1,79 @@
+use crate::constants::MAX_H;
+use crate::libs::color_transform::Color;
+
+/// Color schemes built by rotating the hue of a base color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Harmony {
+  Complementary,
+  Analogous,
+  Triadic,
+  SplitComplementary,
+  Tetradic,
+}
+
+/// Color space the hue is rotated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmonySpace {
+  // HSV and HSL share the hue and keep saturation and value/lightness on rotation, so both give the same colors
+  Hsv,
+  // keeps the perceived lightness and chroma, colors outside of sRGB are clipped
+  Oklch,
+}
+
+pub const HARMONIES: [Harmony; 5] = [
+  Harmony::Complementary,
+  Harmony::Analogous,
+  Harmony::Triadic,
+  Harmony::SplitComplementary,
+  Harmony::Tetradic,
+];
+
+impl Harmony {
+  // hue offsets in degrees, the base color is always the first one
+  pub fn offsets(self) -> &'static [f64] {
+    match self {
+      Harmony::Complementary => &[0.0, 180.0],
+      Harmony::Analogous => &[0.0, -30.0, 30.0],
+      Harmony::Triadic => &[0.0, 120.0, 240.0],
+      Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+      Harmony::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+    }
+  }
+}
+
+fn rotate_hue(hue: f64, offset: f64) -> f64 {
+  (hue + offset).rem_euclid(f64::from(MAX_H))
+}
+
+pub fn rotate(color: &Color, offset: f64, space: HarmonySpace) -> Color {
+  match space {
+    HarmonySpace::Hsv => {
+      let [hue, saturation, value, _] = color.hsva_values();
+      color.with_hsv(rotate_hue(hue, offset), saturation, value)
+    }
+
+    HarmonySpace::Oklch => {
+      let oklch = color.to_oklch();
+      Color::from_oklch_values(
+        oklch.get_lightness(),
+        oklch.get_chroma(),
+        rotate_hue(oklch.get_hue(), offset),
+        oklch.get_alpha(),
+      )
+    }
+  }
+}
+
+pub fn harmony(color: &Color, kind: Harmony, space: HarmonySpace) -> Vec<Color> {
+  kind
+    .offsets()
+    .iter()
+    .map(|offset| {
+      if *offset == 0.0 {
+        color.clone()
+      } else {
+        rotate(color, *offset, space)
+      }
+    })
+    .collect()
+}
@@ -0,0 +1,81 @@
+use std::mem;
+
+/// Undo and redo stacks around the current value. Values recorded while coalescing
+/// (e.g. during a drag) replace each other, so the whole sequence is undone at once.
+pub struct History<T> {
+  undo: Vec<T>,
+  redo: Vec<T>,
+  current: T,
+  // the oldest entries are dropped above it
+  limit: usize,
+  coalescing: bool,
+  coalesced: bool,
+}
+
+impl<T> History<T> {
+  pub fn new(current: T, limit: usize) -> History<T> {
+    History {
+      undo: Vec::new(),
+      redo: Vec::new(),
+      current,
+      limit,
+      coalescing: false,
+      coalesced: false,
+    }
+  }
+
+  pub fn get_current(&self) -> &T {
+    &self.current
+  }
+
+  pub fn can_undo(&self) -> bool {
+    !self.undo.is_empty()
+  }
+
+  pub fn can_redo(&self) -> bool {
+    !self.redo.is_empty()
+  }
+
+  pub fn record(&mut self, value: T) {
+    let previous = mem::replace(&mut self.current, value);
+
+    if !self.coalesced {
+      self.undo.push(previous);
+
+      if self.undo.len() > self.limit {
+        self.undo.remove(0);
+      }
+
+      self.coalesced = self.coalescing;
+    }
+
+    self.redo.clear();
+  }
+
+  pub fn begin_coalescing(&mut self) {
+    self.coalescing = true;
+    self.coalesced = false;
+  }
+
+  pub fn end_coalescing(&mut self) {
+    self.coalescing = false;
+    self.coalesced = false;
+  }
+
+  // returns the value which became current
+  pub fn undo(&mut self) -> Option<&T> {
+    let previous = self.undo.pop()?;
+    self.redo.push(mem::replace(&mut self.current, previous));
+    self.end_coalescing();
+
+    Some(&self.current)
+  }
+
+  pub fn redo(&mut self) -> Option<&T> {
+    let next = self.redo.pop()?;
+    self.undo.push(mem::replace(&mut self.current, next));
+    self.end_coalescing();
+
+    Some(&self.current)
+  }
+}
@@ -1,2 +1,15 @@
+pub mod apca;
+pub mod color_difference;
+pub mod color_scale;
 pub mod color_transform;
+pub mod color_url;
 pub mod color_validate;
+pub mod contrast;
+pub mod css_color;
+pub mod cvd;
+pub mod gradient;
+pub mod harmony;
+pub mod history;
+pub mod named_color;
+pub mod palette;
+pub mod palette_db;
@@ -0,0 +1,258 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::libs::color_transform::Color;
+use crate::libs::css_color::color_space::{lab_to_xyz_d50, linear_to_srgb, xyz_d50_to_linear_srgb};
+use crate::libs::palette::{Swatch, SwatchGroup};
+
+const SIGNATURE: &[u8; 4] = b"ASEF";
+const VERSION: [u16; 2] = [1, 0];
+
+const GROUP_START: u16 = 0xc001;
+const GROUP_END: u16 = 0xc002;
+const COLOR_ENTRY: u16 = 0x0001;
+
+// the length of a name is stored in 16 bits with the terminating zero, longer names are cut,
+// which keeps every block far below the 32 bit length limit as well
+const MAX_NAME_UNITS: usize = u16::MAX as usize - 1;
+
+// global, spot and normal colors are read the same way, normal ones are written
+const NORMAL_COLOR: u16 = 2;
+
+/// Describes why Adobe Swatch Exchange data could not be read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AseError {
+  /// The data does not start with the "ASEF" signature.
+  InvalidSignature,
+  /// The data ends in the middle of the header or a block.
+  UnexpectedEnd,
+  /// A color uses a model other than "RGB ", "CMYK", "LAB " and "Gray".
+  UnknownColorModel(String),
+}
+
+impl fmt::Display for AseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      AseError::InvalidSignature => f.write_str("not an Adobe Swatch Exchange file"),
+      AseError::UnexpectedEnd => f.write_str("unexpected end of the swatch file"),
+      AseError::UnknownColorModel(model) => write!(f, "unknown color model: \"{}\"", model),
+    }
+  }
+}
+
+impl Error for AseError {}
+
+// big endian reader over a slice, every read fails with UnexpectedEnd past its end
+struct Reader<'a> {
+  bytes: &'a [u8],
+  position: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn new(bytes: &'a [u8]) -> Reader<'a> {
+    Reader { bytes, position: 0 }
+  }
+
+  fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], AseError> {
+    let end = self
+      .position
+      .checked_add(count)
+      .ok_or(AseError::UnexpectedEnd)?;
+    let bytes = self
+      .bytes
+      .get(self.position..end)
+      .ok_or(AseError::UnexpectedEnd)?;
+
+    self.position = end;
+    Ok(bytes)
+  }
+
+  fn read_u16(&mut self) -> Result<u16, AseError> {
+    let bytes = self.read_bytes(2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+  }
+
+  fn read_u32(&mut self) -> Result<u32, AseError> {
+    let bytes = self.read_bytes(4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+  }
+
+  fn read_f32(&mut self) -> Result<f64, AseError> {
+    Ok(f64::from(f32::from_bits(self.read_u32()?)))
+  }
+
+  // UTF-16 with the length in code units, the terminating zero included
+  fn read_name(&mut self) -> Result<String, AseError> {
+    let length = usize::from(self.read_u16()?);
+    let units = (0..length)
+      .map(|_| self.read_u16())
+      .collect::<Result<Vec<u16>, AseError>>()?;
+
+    let name = String::from_utf16_lossy(&units);
+    Ok(name.trim_end_matches('\0').to_string())
+  }
+}
+
+fn read_color(reader: &mut Reader) -> Result<Color, AseError> {
+  let model = reader.read_bytes(4)?;
+  let channel = |value: f64| value.clamp(0.0, 1.0);
+
+  let color = match model {
+    b"RGB " => {
+      let [red, green, blue] = [reader.read_f32()?, reader.read_f32()?, reader.read_f32()?];
+      Color::from_srgb_values(channel(red), channel(green), channel(blue), 1.0)
+    }
+
+    b"CMYK" => {
+      let [cyan, magenta, yellow, black] = [
+        reader.read_f32()?,
+        reader.read_f32()?,
+        reader.read_f32()?,
+        reader.read_f32()?,
+      ];
+      let white = 1.0 - channel(black);
+
+      Color::from_srgb_values(
+        (1.0 - channel(cyan)) * white,
+        (1.0 - channel(magenta)) * white,
+        (1.0 - channel(yellow)) * white,
+        1.0,
+      )
+    }
+
+    // lightness is stored in [0,1], Lab is relative to D50 like everywhere in Adobe tools
+    b"LAB " => {
+      let [lightness, a, b] = [reader.read_f32()?, reader.read_f32()?, reader.read_f32()?];
+      let linear = xyz_d50_to_linear_srgb(lab_to_xyz_d50(lightness * 100.0, a, b));
+      let [red, green, blue] = linear.map(|value| channel(linear_to_srgb(value)));
+
+      Color::from_srgb_values(red, green, blue, 1.0)
+    }
+
+    b"Gray" => {
+      let gray = channel(reader.read_f32()?);
+      Color::from_srgb_values(gray, gray, gray, 1.0)
+    }
+
+    _ => {
+      return Err(AseError::UnknownColorModel(
+        String::from_utf8_lossy(model).to_string(),
+      ))
+    }
+  };
+
+  Ok(color)
+}
+
+/// Reads swatches from Adobe Swatch Exchange data, swatches outside of any group are put into
+/// a group with the default name.
+pub fn read(bytes: &[u8], default_group: &str) -> Result<Vec<SwatchGroup>, AseError> {
+  let mut reader = Reader::new(bytes);
+
+  if reader.read_bytes(4)? != SIGNATURE {
+    return Err(AseError::InvalidSignature);
+  }
+
+  // only one version has ever been published
+  reader.read_bytes(4)?;
+  let block_count = reader.read_u32()?;
+
+  let mut groups: Vec<SwatchGroup> = Vec::new();
+  let mut in_group = false;
+
+  for _ in 0..block_count {
+    let block_type = reader.read_u16()?;
+    let length = reader.read_u32()? as usize;
+    let mut block = Reader::new(reader.read_bytes(length)?);
+
+    match block_type {
+      GROUP_START => {
+        groups.push(SwatchGroup {
+          name: block.read_name()?,
+          swatches: Vec::new(),
+        });
+        in_group = true;
+      }
+
+      GROUP_END => in_group = false,
+
+      COLOR_ENTRY => {
+        let name = block.read_name()?;
+        let color = read_color(&mut block)?;
+
+        if !in_group {
+          groups.push(SwatchGroup {
+            name: default_group.to_string(),
+            swatches: Vec::new(),
+          });
+          in_group = true;
+        }
+
+        if let Some(group) = groups.last_mut() {
+          group.swatches.push(Swatch { name, color });
+        }
+      }
+
+      // blocks of unknown types are skipped
+      _ => {}
+    }
+  }
+
+  Ok(groups)
+}
+
+fn write_name(bytes: &mut Vec<u8>, name: &str) {
+  let mut units: Vec<u16> = name.encode_utf16().take(MAX_NAME_UNITS).collect();
+
+  // a surrogate pair is not split
+  if units.len() == MAX_NAME_UNITS && matches!(units.last(), Some(0xd800..=0xdbff)) {
+    units.pop();
+  }
+  units.push(0);
+
+  bytes.extend_from_slice(&(units.len() as u16).to_be_bytes());
+  for unit in units {
+    bytes.extend_from_slice(&unit.to_be_bytes());
+  }
+}
+
+fn write_block(bytes: &mut Vec<u8>, block_type: u16, content: &[u8]) {
+  bytes.extend_from_slice(&block_type.to_be_bytes());
+  bytes.extend_from_slice(&(content.len() as u32).to_be_bytes());
+  bytes.extend_from_slice(content);
+}
+
+/// Writes the groups as Adobe Swatch Exchange data, colors are stored as RGB without opacity.
+pub fn write(groups: &[SwatchGroup]) -> Vec<u8> {
+  // every block takes at least 6 bytes of the output, so the count of anything that fits into
+  // the 32 bit address space of wasm fits into the 32 bit field as well
+  let block_count: usize = groups.iter().map(|group| group.swatches.len() + 2).sum();
+
+  let mut bytes = Vec::new();
+  bytes.extend_from_slice(SIGNATURE);
+  bytes.extend_from_slice(&VERSION[0].to_be_bytes());
+  bytes.extend_from_slice(&VERSION[1].to_be_bytes());
+  bytes.extend_from_slice(&(block_count as u32).to_be_bytes());
+
+  for group in groups {
+    let mut content = Vec::new();
+    write_name(&mut content, &group.name);
+    write_block(&mut bytes, GROUP_START, &content);
+
+    for swatch in group.swatches.iter() {
+      let mut content = Vec::new();
+      write_name(&mut content, &swatch.name);
+      content.extend_from_slice(b"RGB ");
+      for channel in swatch.color.srgb_values() {
+        content.extend_from_slice(&(channel as f32).to_be_bytes());
+      }
+      content.extend_from_slice(&NORMAL_COLOR.to_be_bytes());
+
+      write_block(&mut bytes, COLOR_ENTRY, &content);
+    }
+
+    write_block(&mut bytes, GROUP_END, &[]);
+  }
+
+  bytes
+}
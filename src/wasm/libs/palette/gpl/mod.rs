@@ -0,0 +1,72 @@
+use std::fmt::Write;
+
+use crate::libs::palette::{
+  parse_rgb_channels, rgb_channels, swatch_name, PaletteFileError, Swatch, SwatchGroup,
+};
+
+const HEADER: &str = "GIMP Palette";
+const NAME_PREFIX: &str = "Name:";
+const COLUMNS_PREFIX: &str = "Columns:";
+
+/// Reads a GIMP palette (also used by Inkscape and Krita), the group is named after the
+/// "Name:" line of the file or gets the default name.
+///
+/// ```text
+/// GIMP Palette
+/// Name: Brand
+/// #
+/// 227   6  19    Brand red
+/// ```
+pub fn read(source: &str, default_group: &str) -> Result<SwatchGroup, PaletteFileError> {
+  let mut lines = source.trim_start_matches('\u{feff}').lines().enumerate();
+
+  match lines.next() {
+    Some((_, line)) if line.trim() == HEADER => {}
+    _ => return Err(PaletteFileError::MissingHeader),
+  }
+
+  let mut group = SwatchGroup {
+    name: default_group.to_string(),
+    swatches: Vec::new(),
+  };
+
+  for (index, line) in lines {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with('#') || line.starts_with(COLUMNS_PREFIX) {
+      continue;
+    }
+
+    if let Some(name) = line.strip_prefix(NAME_PREFIX) {
+      if !name.trim().is_empty() {
+        group.name = name.trim().to_string();
+      }
+      continue;
+    }
+
+    let (color, name) =
+      parse_rgb_channels(line).ok_or(PaletteFileError::InvalidColor { line: index + 1 })?;
+
+    group.swatches.push(Swatch {
+      name: swatch_name(name, &color),
+      color,
+    });
+  }
+
+  Ok(group)
+}
+
+/// Writes the group as a GIMP palette, opacity is not stored.
+pub fn write(group: &SwatchGroup) -> String {
+  let mut source = format!(
+    "{}\n{} {}\n{} 0\n#\n",
+    HEADER, NAME_PREFIX, group.name, COLUMNS_PREFIX
+  );
+
+  for swatch in group.swatches.iter() {
+    let [red, green, blue] = rgb_channels(&swatch.color);
+    let _ = writeln!(source, "{:3} {:3} {:3}\t{}", red, green, blue, swatch.name);
+  }
+
+  source
+}
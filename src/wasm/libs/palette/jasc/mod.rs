@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use crate::libs::palette::{
+  parse_rgb_channels, rgb_channels, swatch_name, PaletteFileError, Swatch, SwatchGroup,
+};
+
+const HEADER: &str = "JASC-PAL";
+const VERSION: &str = "0100";
+// Paint Shop Pro expects Windows line endings
+const LINE_END: &str = "\r\n";
+
+/// Reads a JASC (Paint Shop Pro) palette, the format has no names so the group gets the
+/// default name and the swatches are called by their hex values.
+///
+/// ```text
+/// JASC-PAL
+/// 0100
+/// 2
+/// 227 6 19
+/// 242 227 198
+/// ```
+pub fn read(source: &str, default_group: &str) -> Result<SwatchGroup, PaletteFileError> {
+  let mut lines = source
+    .trim_start_matches('\u{feff}')
+    .lines()
+    .enumerate()
+    .map(|(index, line)| (index + 1, line.trim()));
+
+  match (lines.next(), lines.next()) {
+    (Some((_, HEADER)), Some((_, VERSION))) => {}
+    _ => return Err(PaletteFileError::MissingHeader),
+  }
+
+  let count = match lines.next() {
+    Some((line, value)) => value
+      .parse::<usize>()
+      .map_err(|_| PaletteFileError::InvalidColorCount { line })?,
+    None => return Err(PaletteFileError::UnexpectedEnd),
+  };
+
+  let mut swatches = Vec::new();
+
+  for (line, value) in lines.filter(|(_, value)| !value.is_empty()).take(count) {
+    let color = match parse_rgb_channels(value) {
+      Some((color, "")) => color,
+      _ => return Err(PaletteFileError::InvalidColor { line }),
+    };
+
+    swatches.push(Swatch {
+      name: swatch_name("", &color),
+      color,
+    });
+  }
+
+  if swatches.len() < count {
+    return Err(PaletteFileError::UnexpectedEnd);
+  }
+
+  Ok(SwatchGroup {
+    name: default_group.to_string(),
+    swatches,
+  })
+}
+
+/// Writes the colors of the group as a JASC palette, names and opacity are not stored.
+pub fn write(group: &SwatchGroup) -> String {
+  let mut source = [HEADER, VERSION, &group.swatches.len().to_string(), ""].join(LINE_END);
+
+  for swatch in group.swatches.iter() {
+    let [red, green, blue] = rgb_channels(&swatch.color);
+    let _ = write!(source, "{} {} {}{}", red, green, blue, LINE_END);
+  }
+
+  source
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+use crate::libs::color_transform::Color;
+
+pub mod ase;
+pub mod gpl;
+pub mod jasc;
+pub mod paint_net;
+pub mod storage;
+
+use storage::PaletteStorage;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Swatch {
+  pub name: String,
+  pub color: Color,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwatchGroup {
+  pub name: String,
+  pub swatches: Vec<Swatch>,
+}
+
+/// Describes why a text palette file (GIMP, JASC or Paint.NET) could not be read, lines are
+/// counted from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteFileError {
+  /// The file does not start with the header of the format.
+  MissingHeader,
+  /// The line does not hold a color of the format.
+  InvalidColor { line: usize },
+  /// The number of colors in the header is not a number.
+  InvalidColorCount { line: usize },
+  /// The file has fewer colors than its header announces.
+  UnexpectedEnd,
+}
+
+impl fmt::Display for PaletteFileError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PaletteFileError::MissingHeader => f.write_str("unknown palette format"),
+      PaletteFileError::InvalidColor { line } => write!(f, "line {}: invalid color", line),
+      PaletteFileError::InvalidColorCount { line } => {
+        write!(f, "line {}: invalid number of colors", line)
+      }
+      PaletteFileError::UnexpectedEnd => f.write_str("unexpected end of the palette file"),
+    }
+  }
+}
+
+impl Error for PaletteFileError {}
+
+/// Named swatches organized in groups, every change is written to the storage right away.
+///
+/// There is always at least one group, so the current color can be added at any time.
+pub struct Palette {
+  groups: Vec<SwatchGroup>,
+  storage: Box<dyn PaletteStorage>,
+}
+
+impl Palette {
+  // starts from the groups saved before or from a single empty group
+  pub fn load(storage: Box<dyn PaletteStorage>, default_group: &str) -> Palette {
+    let groups = storage
+      .load()
+      .filter(|groups| !groups.is_empty())
+      .unwrap_or_else(|| {
+        vec![SwatchGroup {
+          name: default_group.to_string(),
+          swatches: Vec::new(),
+        }]
+      });
+
+    Palette { groups, storage }
+  }
+
+  pub fn get_groups(&self) -> &[SwatchGroup] {
+    &self.groups
+  }
+
+  pub fn get_swatch(&self, group: usize, index: usize) -> Option<&Swatch> {
+    self.groups.get(group)?.swatches.get(index)
+  }
+
+  // returns the index of the new group
+  pub fn add_group(&mut self, name: String) -> usize {
+    self.groups.push(SwatchGroup {
+      name,
+      swatches: Vec::new(),
+    });
+    self.save();
+
+    self.groups.len() - 1
+  }
+
+  // appends imported groups after the existing ones
+  pub fn add_groups(&mut self, groups: Vec<SwatchGroup>) {
+    self.groups.extend(groups);
+    self.save();
+  }
+
+  // the last group is kept
+  pub fn remove_group(&mut self, group: usize) -> bool {
+    if self.groups.len() <= 1 || group >= self.groups.len() {
+      return false;
+    }
+
+    self.groups.remove(group);
+    self.save();
+    true
+  }
+
+  pub fn rename_group(&mut self, group: usize, name: String) -> bool {
+    match self.groups.get_mut(group) {
+      Some(swatch_group) => swatch_group.name = name,
+      None => return false,
+    }
+
+    self.save();
+    true
+  }
+
+  // returns the index of the new swatch inside of the group
+  pub fn add_swatch(&mut self, group: usize, name: String, color: Color) -> Option<usize> {
+    let swatches = &mut self.groups.get_mut(group)?.swatches;
+    swatches.push(Swatch { name, color });
+    let index = swatches.len() - 1;
+
+    self.save();
+    Some(index)
+  }
+
+  pub fn remove_swatch(&mut self, group: usize, index: usize) -> bool {
+    match self.groups.get_mut(group) {
+      Some(swatch_group) if index < swatch_group.swatches.len() => {
+        swatch_group.swatches.remove(index);
+      }
+      _ => return false,
+    }
+
+    self.save();
+    true
+  }
+
+  // moves a swatch inside of its group, the other swatches are shifted
+  pub fn move_swatch(&mut self, group: usize, from: usize, to: usize) -> bool {
+    match self.groups.get_mut(group) {
+      Some(swatch_group) if from < swatch_group.swatches.len() => {
+        let to = to.min(swatch_group.swatches.len() - 1);
+        let swatch = swatch_group.swatches.remove(from);
+        swatch_group.swatches.insert(to, swatch);
+      }
+      _ => return false,
+    }
+
+    self.save();
+    true
+  }
+
+  fn save(&mut self) {
+    self.storage.save(&self.groups);
+  }
+}
+
+// 8 bit channels of the text formats, alpha is dropped
+fn rgb_channels(color: &Color) -> [u8; 3] {
+  color
+    .srgb_values()
+    .map(|channel| (channel * 255.0).round() as u8)
+}
+
+// "R G B" in [0,255] separated by whitespace, returns the rest of the line
+fn parse_rgb_channels(line: &str) -> Option<(Color, &str)> {
+  let mut rest = line.trim_start();
+  let mut channels = [0.0; 3];
+
+  for channel in channels.iter_mut() {
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    *channel = f64::from(rest[..end].parse::<u8>().ok()?) / 255.0;
+    rest = rest[end..].trim_start();
+  }
+
+  let [red, green, blue] = channels;
+  Some((Color::from_srgb_values(red, green, blue, 1.0), rest))
+}
+
+// swatches without a name are called by their hex value
+pub(crate) fn swatch_name(name: &str, color: &Color) -> String {
+  let name = name.trim();
+
+  if name.is_empty() {
+    color.hex_value()
+  } else {
+    name.to_string()
+  }
+}
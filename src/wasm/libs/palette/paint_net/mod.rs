@@ -0,0 +1,91 @@
+use std::fmt::Write;
+
+use crate::libs::color_transform::Color;
+use crate::libs::palette::{rgb_channels, swatch_name, PaletteFileError, Swatch, SwatchGroup};
+
+const COMMENT: char = ';';
+const HEADER: &str = "; paint.net Palette File";
+// Paint.NET fills missing slots with white and ignores the colors after the 96th one
+pub const MAX_COLORS: usize = 96;
+const DESCRIPTION: [&str; 3] = [
+  "; Lines that start with a semicolon are comments.",
+  "; Colors are written as 8-digit hexadecimal numbers: aarrggbb.",
+  "; A palette holds up to ninety six (96) colors.",
+];
+const LINE_END: &str = "\r\n";
+
+// AARRGGBB
+fn parse_color(value: &str) -> Option<Color> {
+  // from_str_radix would take a leading "+" as well
+  if value.len() != 8 || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+    return None;
+  }
+
+  let [alpha, red, green, blue] = u32::from_str_radix(value, 16).ok()?.to_be_bytes();
+  let channel = |value: u8| f64::from(value) / 255.0;
+
+  Some(Color::from_srgb_values(
+    channel(red),
+    channel(green),
+    channel(blue),
+    channel(alpha),
+  ))
+}
+
+/// Reads a Paint.NET palette, the format has no names so the group gets the default name and
+/// the swatches are called by their hex values. Unlike the other text formats it keeps opacity.
+///
+/// ```text
+/// ; paint.net Palette File
+/// FFE30613
+/// 80F2E3C6
+/// ```
+pub fn read(source: &str, default_group: &str) -> Result<SwatchGroup, PaletteFileError> {
+  let mut swatches = Vec::new();
+
+  for (index, line) in source.trim_start_matches('\u{feff}').lines().enumerate() {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with(COMMENT) {
+      continue;
+    }
+
+    let color = parse_color(line).ok_or(PaletteFileError::InvalidColor { line: index + 1 })?;
+
+    swatches.push(Swatch {
+      name: swatch_name("", &color),
+      color,
+    });
+  }
+
+  Ok(SwatchGroup {
+    name: default_group.to_string(),
+    swatches,
+  })
+}
+
+/// Writes the colors of the group as a Paint.NET palette, names are not stored and only the
+/// first 96 colors are written since Paint.NET ignores the rest. Returns the palette and the
+/// number of colors left out.
+pub fn write(group: &SwatchGroup) -> (String, usize) {
+  let mut source = String::new();
+
+  for line in [HEADER].iter().chain(DESCRIPTION.iter()) {
+    let _ = write!(source, "{}{}", line, LINE_END);
+  }
+
+  for swatch in group.swatches.iter().take(MAX_COLORS) {
+    let [red, green, blue] = rgb_channels(&swatch.color);
+    let [.., alpha] = swatch.color.hsva_values();
+    let alpha = (alpha * 255.0).round() as u8;
+
+    let _ = write!(
+      source,
+      "{:02X}{:02X}{:02X}{:02X}{}",
+      alpha, red, green, blue, LINE_END
+    );
+  }
+
+  let dropped = group.swatches.len().saturating_sub(MAX_COLORS);
+  (source, dropped)
+}
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo::storage::{LocalStorage as BrowserStorage, Storage};
+
+use crate::libs::palette::SwatchGroup;
+
+/// Where a palette is kept between sessions.
+pub trait PaletteStorage {
+  fn load(&self) -> Option<Vec<SwatchGroup>>;
+  fn save(&mut self, groups: &[SwatchGroup]);
+}
+
+/// Keeps the palette in the browser's localStorage under the given key.
+pub struct LocalStorage {
+  key: String,
+}
+
+impl LocalStorage {
+  pub fn new(key: &str) -> LocalStorage {
+    LocalStorage {
+      key: key.to_string(),
+    }
+  }
+}
+
+impl PaletteStorage for LocalStorage {
+  // a missing or broken entry gives an empty palette instead of an error
+  fn load(&self) -> Option<Vec<SwatchGroup>> {
+    BrowserStorage::get(&self.key).ok()
+  }
+
+  // quota errors are ignored, the palette is still usable until the page is reloaded
+  fn save(&mut self, groups: &[SwatchGroup]) {
+    let _ = BrowserStorage::set(&self.key, groups);
+  }
+}
+
+/// Keeps the palette in memory, clones share the same data so a palette can be loaded again.
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+  groups: Rc<RefCell<Option<Vec<SwatchGroup>>>>,
+}
+
+impl MemoryStorage {
+  pub fn new() -> MemoryStorage {
+    MemoryStorage::default()
+  }
+}
+
+impl PaletteStorage for MemoryStorage {
+  fn load(&self) -> Option<Vec<SwatchGroup>> {
+    self.groups.borrow().clone()
+  }
+
+  fn save(&mut self, groups: &[SwatchGroup]) {
+    *self.groups.borrow_mut() = Some(groups.to_vec());
+  }
+}
@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::libs::color_difference;
+use crate::libs::color_transform::lab_color::Lab;
+use crate::libs::color_transform::parse_error::ParseColorError;
+use crate::libs::color_transform::Color;
+
+/// Palettes which ship with the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinPalette {
+  Material,
+  Tailwind,
+  RalClassic,
+}
+
+pub const BUILTIN_PALETTES: [BuiltinPalette; 3] = [
+  BuiltinPalette::Material,
+  BuiltinPalette::Tailwind,
+  BuiltinPalette::RalClassic,
+];
+
+impl BuiltinPalette {
+  fn source(self) -> &'static str {
+    match self {
+      BuiltinPalette::Material => include_str!("data/material.txt"),
+      BuiltinPalette::Tailwind => include_str!("data/tailwind.txt"),
+      BuiltinPalette::RalClassic => include_str!("data/ral_classic.txt"),
+    }
+  }
+
+  pub fn load(self) -> PaletteDb {
+    PaletteDb::parse(self.source()).expect("built-in palettes are valid")
+  }
+}
+
+/// Describes why a palette file could not be read, lines are counted from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteDbError {
+  /// The line has no "=" between the name and the color.
+  MissingSeparator { line: usize },
+  /// The name in front of "=" is empty.
+  MissingName { line: usize },
+  /// The color is neither a hex value nor "lab(L, a, b)".
+  InvalidColor { line: usize, error: ParseColorError },
+}
+
+impl fmt::Display for PaletteDbError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PaletteDbError::MissingSeparator { line } => {
+        write!(f, "line {}: expected \"name = color\"", line)
+      }
+      PaletteDbError::MissingName { line } => write!(f, "line {}: the name is empty", line),
+      PaletteDbError::InvalidColor { line, error } => write!(f, "line {}: {}", line, error),
+    }
+  }
+}
+
+impl Error for PaletteDbError {}
+
+#[derive(Clone)]
+pub struct PaletteEntry {
+  pub name: String,
+  pub color: Color,
+}
+
+/// Entry of a palette with its distance to the color it was matched against.
+#[derive(Clone, Copy)]
+pub struct PaletteMatch<'a> {
+  pub entry: &'a PaletteEntry,
+  // CIEDE2000, 0 for an exact match
+  pub delta_e: f64,
+}
+
+/// Table of named colors to search in.
+///
+/// The file format has one color per line as `name = color`, where the color is a hex value or
+/// `lab(L, a, b)` relative to D65. Empty lines and lines starting with `#` are skipped:
+///
+/// ```text
+/// # corporate colors
+/// Brand red = #e30613
+/// Brand sand = lab(82.5, 2.1, 18.4)
+/// ```
+pub struct PaletteDb {
+  entries: Vec<PaletteEntry>,
+  // cached for the search, in the order of the entries
+  labs: Vec<Lab>,
+}
+
+fn parse_color(value: &str) -> Result<Color, ParseColorError> {
+  let lower = value.to_ascii_lowercase();
+
+  match lower
+    .strip_prefix("lab(")
+    .and_then(|rest| rest.strip_suffix(')'))
+  {
+    Some(components) => Color::from_lab(components.to_string()),
+    None => Color::from_hex(value.to_string()),
+  }
+}
+
+impl PaletteDb {
+  pub fn new(entries: Vec<PaletteEntry>) -> PaletteDb {
+    let labs = entries.iter().map(|entry| entry.color.to_lab()).collect();
+
+    PaletteDb { entries, labs }
+  }
+
+  pub fn parse(source: &str) -> Result<PaletteDb, PaletteDbError> {
+    let mut entries = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+      let line_number = index + 1;
+      let line = line.trim();
+
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      // names may contain "=", the color never does
+      let (name, value) = match line.rsplit_once('=') {
+        Some(pair) => pair,
+        None => return Err(PaletteDbError::MissingSeparator { line: line_number }),
+      };
+
+      let name = name.trim();
+      if name.is_empty() {
+        return Err(PaletteDbError::MissingName { line: line_number });
+      }
+
+      let color = parse_color(value.trim()).map_err(|error| PaletteDbError::InvalidColor {
+        line: line_number,
+        error,
+      })?;
+
+      entries.push(PaletteEntry {
+        name: name.to_string(),
+        color,
+      });
+    }
+
+    Ok(PaletteDb::new(entries))
+  }
+
+  pub fn get_entries(&self) -> &[PaletteEntry] {
+    &self.entries
+  }
+
+  pub fn find(&self, name: &str) -> Option<&PaletteEntry> {
+    let name = name.trim();
+
+    self
+      .entries
+      .iter()
+      .find(|entry| entry.name.eq_ignore_ascii_case(name))
+  }
+
+  // up to `count` entries from the closest one, alpha is ignored
+  pub fn closest(&self, color: &Color, count: usize) -> Vec<PaletteMatch<'_>> {
+    let lab = color.to_lab();
+
+    let mut matches: Vec<PaletteMatch> = self
+      .entries
+      .iter()
+      .zip(self.labs.iter())
+      .map(|(entry, entry_lab)| PaletteMatch {
+        entry,
+        delta_e: color_difference::ciede2000(&lab, entry_lab),
+      })
+      .collect();
+
+    matches.sort_by(|first, second| first.delta_e.total_cmp(&second.delta_e));
+    matches.truncate(count);
+
+    matches
+  }
+}
@@ -0,0 +1,37 @@
+use gloo::file::Blob;
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlElement, Url};
+use yew::utils::document;
+
+// time the browser gets to start the download before the object URL is released
+const REVOKE_DELAY: u32 = 1000;
+
+/// A service that saves generated content as a file on the user's machine.
+#[derive(Debug, Default)]
+pub struct DownloadService {}
+
+impl DownloadService {
+  /// Creates a new DownloadService.
+  pub fn new() -> DownloadService {
+    DownloadService {}
+  }
+
+  /// Starts the download of the content under the given file name.
+  pub fn download(&self, file_name: &str, content: &[u8], mime_type: &str) -> Result<(), JsValue> {
+    let blob: web_sys::Blob = Blob::new_with_options(content, Some(mime_type)).into();
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let link = document().create_element("a")?.dyn_into::<HtmlElement>()?;
+    link.set_attribute("href", &url)?;
+    link.set_attribute("download", file_name)?;
+    link.click();
+
+    Timeout::new(REVOKE_DELAY, move || {
+      let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+
+    Ok(())
+  }
+}
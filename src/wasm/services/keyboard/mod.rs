@@ -0,0 +1,54 @@
+use std::fmt;
+
+use gloo::events::EventListener;
+use web_sys::{Event, KeyboardEvent};
+use yew::utils::document;
+
+use wasm_bindgen::JsCast;
+
+use yew::callback::Callback;
+
+/// A service that fires keyboard events of the whole page.
+#[derive(Debug)]
+pub struct KeyboardService {
+  event: String,
+}
+
+/// A handle to the event listener for keyboard events.
+#[must_use]
+#[allow(dead_code)]
+pub struct KeyboardTask {
+  handle: EventListener,
+}
+
+impl fmt::Debug for KeyboardTask {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("KeyboardTask")
+  }
+}
+
+impl KeyboardService {
+  /// Creates a new KeyboardService.
+  pub fn new(event: String) -> KeyboardService {
+    KeyboardService { event }
+  }
+
+  /// Register a callback that will be called when a key is pressed.
+  pub fn register(&mut self, callback: Callback<KeyboardEvent>) -> KeyboardTask {
+    let callback = move |event: &Event| {
+      let keyboard_event = event
+        .dyn_ref::<KeyboardEvent>()
+        .expect("wrong event type")
+        .clone();
+
+      callback.emit(keyboard_event);
+    };
+
+    let handle = EventListener::new(
+      &document().body().unwrap().into(),
+      self.event.to_string(),
+      callback,
+    );
+    KeyboardTask { handle }
+  }
+}
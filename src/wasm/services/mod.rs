@@ -1 +1,3 @@
+pub mod download;
+pub mod keyboard;
 pub mod mouse;
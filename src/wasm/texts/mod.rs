@@ -1,17 +1,145 @@
 pub struct Texts<'a> {
   pub title: &'a str,
   pub cmyk: &'a str,
+  pub contrast: &'a str,
+  pub contrast_white: &'a str,
+  pub contrast_black: &'a str,
+  pub contrast_sample: &'a str,
+  pub wcag_aa: &'a str,
+  pub wcag_aaa: &'a str,
+  pub wcag_aa_large: &'a str,
+  pub wcag_aaa_large: &'a str,
+  pub cvd: &'a str,
+  pub cvd_simulate: &'a str,
+  pub cvd_protan: &'a str,
+  pub cvd_deutan: &'a str,
+  pub cvd_tritan: &'a str,
+  pub cvd_severity: &'a str,
+  pub cvd_model: &'a str,
+  pub cvd_machado: &'a str,
+  pub cvd_brettel: &'a str,
+  pub gradient: &'a str,
+  pub gradient_space: &'a str,
+  pub gradient_shape: &'a str,
+  pub gradient_linear: &'a str,
+  pub gradient_radial: &'a str,
+  pub gradient_conic: &'a str,
+  pub gradient_angle: &'a str,
+  pub gradient_remove_stop: &'a str,
+  pub harmony: &'a str,
+  pub harmony_complementary: &'a str,
+  pub harmony_analogous: &'a str,
+  pub harmony_triadic: &'a str,
+  pub harmony_split_complementary: &'a str,
+  pub harmony_tetradic: &'a str,
+  pub harmony_oklch: &'a str,
   pub hex: &'a str,
   pub hsl: &'a str,
   pub hsv: &'a str,
+  pub lab: &'a str,
+  pub lch: &'a str,
+  pub nearest_name: &'a str,
+  pub oklch: &'a str,
+  pub palette: &'a str,
+  pub palette_default_group: &'a str,
+  pub palette_group: &'a str,
+  pub palette_name: &'a str,
+  pub palette_add_group: &'a str,
+  pub palette_remove_group: &'a str,
+  pub palette_rename_group: &'a str,
+  pub palette_add_color: &'a str,
+  pub palette_remove_color: &'a str,
+  pub palette_move_left: &'a str,
+  pub palette_move_right: &'a str,
+  pub palette_export: &'a str,
+  pub palette_format_ase: &'a str,
+  pub palette_format_gpl: &'a str,
+  pub palette_format_jasc: &'a str,
+  pub palette_format_paint_net: &'a str,
+  pub palette_import: &'a str,
+  pub palette_import_error: &'a str,
+  pub palette_colors_left_out: &'a str,
+  pub palette_unknown_format: &'a str,
+  pub palette_match: &'a str,
+  pub palette_match_material: &'a str,
+  pub palette_match_tailwind: &'a str,
+  pub palette_match_ral: &'a str,
   pub rgb: &'a str,
+  pub scale_tint: &'a str,
+  pub scale_shade: &'a str,
+  pub scale_tone: &'a str,
+  pub scale_lightness: &'a str,
 }
 
 pub const TEXTS: Texts<'static> = Texts {
   title: "Color picker",
   cmyk: "CMYK",
+  contrast: "Contrast",
+  contrast_white: "White",
+  contrast_black: "Black",
+  contrast_sample: "Aa",
+  wcag_aa: "AA",
+  wcag_aaa: "AAA",
+  wcag_aa_large: "AA Large",
+  wcag_aaa_large: "AAA Large",
+  cvd: "Color vision",
+  cvd_simulate: "Simulate color vision deficiency",
+  cvd_protan: "Protan (red)",
+  cvd_deutan: "Deutan (green)",
+  cvd_tritan: "Tritan (blue)",
+  cvd_severity: "Severity",
+  cvd_model: "Model",
+  cvd_machado: "Machado",
+  cvd_brettel: "Brettel",
+  gradient: "Gradient",
+  gradient_space: "Interpolation",
+  gradient_shape: "Shape",
+  gradient_linear: "Linear",
+  gradient_radial: "Radial",
+  gradient_conic: "Conic",
+  gradient_angle: "Angle",
+  gradient_remove_stop: "Remove stop",
+  harmony: "Harmony",
+  harmony_complementary: "Complementary",
+  harmony_analogous: "Analogous",
+  harmony_triadic: "Triadic",
+  harmony_split_complementary: "Split complementary",
+  harmony_tetradic: "Tetradic",
+  harmony_oklch: "Rotate in OKLCH",
   hex: "HEX",
   hsl: "HSL",
   hsv: "HSV",
+  lab: "LAB",
+  lch: "LCH",
+  nearest_name: "Nearest name",
+  oklch: "OKLCH",
+  palette: "Palette",
+  palette_default_group: "Saved colors",
+  palette_group: "Group",
+  palette_name: "Name",
+  palette_add_group: "Add group",
+  palette_remove_group: "Remove group",
+  palette_rename_group: "Rename",
+  palette_add_color: "Add color",
+  palette_remove_color: "Remove",
+  palette_move_left: "Move left",
+  palette_move_right: "Move right",
+  palette_export: "Export",
+  palette_format_ase: "Adobe (.ase)",
+  palette_format_gpl: "GIMP (.gpl)",
+  palette_format_jasc: "JASC (.pal)",
+  palette_format_paint_net: "Paint.NET (.txt)",
+  palette_import: "Import",
+  palette_import_error: "Could not import the palette",
+  palette_colors_left_out: "Colors the format cannot hold were left out",
+  palette_unknown_format: "unsupported file type",
+  palette_match: "Closest matches",
+  palette_match_material: "Material Design",
+  palette_match_tailwind: "Tailwind",
+  palette_match_ral: "RAL Classic",
   rgb: "RGB",
+  scale_tint: "Tints",
+  scale_shade: "Shades",
+  scale_tone: "Tones",
+  scale_lightness: "Lightness",
 };
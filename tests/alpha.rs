@@ -0,0 +1,53 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::color_validate::{is_valid_cmyk, is_valid_hsl, is_valid_hsv, is_valid_rgb};
+use color_picker::libs::css_color::{self, CssFormat};
+
+#[test]
+fn should_parse_and_print_hex_with_alpha() {
+  let color = Color::from_hex(String::from("#0c223880")).unwrap();
+  assert_eq!(color.hex_value(), "#0c223880");
+  assert_eq!(color.rgb_value(), "12, 34, 56, 0.5");
+  assert_eq!(color.opaque_color_value(), "rgb(12, 34, 56)");
+}
+
+#[test]
+fn should_keep_alpha_across_models() {
+  let color = Color::from_rgb(String::from("255, 0, 0, 0.25")).unwrap();
+  assert_eq!(color.get_alpha(), 0.25);
+  assert_eq!(color.hex_value(), "#ff000040");
+  assert_eq!(color.hsl_value(), "0°, 100%, 50%, 0.25");
+  assert_eq!(color.hsv_value(), "0°, 100%, 100%, 0.25");
+  assert_eq!(color.cmyk_value(), "0%, 100%, 100%, 0%, 0.25");
+}
+
+#[test]
+fn should_print_css_strings_with_alpha() {
+  let color = css_color::parse("rgba(255, 0, 0, 0.5)").unwrap();
+  assert_eq!(color.to_css_string(CssFormat::Rgb), "rgba(255, 0, 0, 0.5)");
+  assert_eq!(
+    color.to_css_string(CssFormat::Hsl),
+    "hsla(0, 100%, 50%, 0.5)"
+  );
+  assert_eq!(color.to_css_string(CssFormat::Hwb), "hwb(0 0% 0% / 0.5)");
+}
+
+#[test]
+fn should_validate_alpha() {
+  assert!(is_valid_rgb("12, 34, 56, 0.5"));
+  assert!(is_valid_cmyk("0%, 100%, 100%, 0%, 1"));
+  assert!(is_valid_hsv("0°, 100%, 100%, 0"));
+  assert!(is_valid_hsl("0°, 100%, 50%, 0.75"));
+
+  assert!(!is_valid_rgb("12, 34, 56, 1.5"));
+  assert!(!is_valid_hsl("0°, 100%, 50%, 2"));
+}
+
+#[test]
+fn should_change_alpha_without_moving_channels() {
+  let color = Color::from_rgb_values(12.0, 34.0, 56.0);
+  let changed = color.with_alpha(0.37);
+
+  assert_eq!(changed.srgb_values(), color.srgb_values());
+  assert_eq!(changed.hsva_values()[3], 0.37);
+  assert_eq!(changed.with_alpha(1.0).srgb_values(), color.srgb_values());
+}
@@ -0,0 +1,59 @@
+use color_picker::libs::apca::{self, FontUse};
+use color_picker::libs::color_transform::Color;
+
+fn round(value: f64) -> f64 {
+  (value * 100.0).round() / 100.0
+}
+
+#[test]
+fn should_calculate_lightness_contrast() {
+  let white = Color::from_hex(String::from("#ffffff")).unwrap();
+  let black = Color::from_hex(String::from("#000000")).unwrap();
+  let grey = Color::from_hex(String::from("#888888")).unwrap();
+
+  assert_eq!(round(apca::lightness_contrast(&black, &white)), 106.04);
+  assert_eq!(round(apca::lightness_contrast(&white, &black)), -107.88);
+  assert_eq!(round(apca::lightness_contrast(&grey, &white)), 63.06);
+  assert_eq!(round(apca::lightness_contrast(&white, &grey)), -68.54);
+}
+
+#[test]
+fn should_clip_low_contrast() {
+  let grey = Color::from_hex(String::from("#888888")).unwrap();
+  let light_grey = Color::from_hex(String::from("#8a8a8a")).unwrap();
+
+  assert_eq!(apca::lightness_contrast(&grey, &grey), 0.0);
+  assert_eq!(apca::lightness_contrast(&grey, &light_grey), 0.0);
+}
+
+#[test]
+fn should_look_up_minimum_font_size() {
+  assert_eq!(
+    apca::minimum_font_size(90.0, 400),
+    Some(FontUse::MinimumSize(16.0))
+  );
+  assert_eq!(
+    apca::minimum_font_size(-75.0, 300),
+    Some(FontUse::MinimumSize(24.0))
+  );
+  assert_eq!(
+    apca::minimum_font_size(62.5, 400),
+    Some(FontUse::MinimumSize(22.875))
+  );
+  assert_eq!(
+    apca::minimum_font_size(200.0, 400),
+    Some(FontUse::MinimumSize(12.5))
+  );
+  assert_eq!(apca::minimum_font_size(90.0, 450), None);
+}
+
+#[test]
+fn should_restrict_weights_at_low_contrast() {
+  assert_eq!(apca::minimum_font_size(37.5, 100), Some(FontUse::NonText));
+  assert_eq!(
+    apca::minimum_font_size(37.5, 200),
+    Some(FontUse::MinimumSize(114.0))
+  );
+  assert_eq!(apca::minimum_font_sizes(20.0), [FontUse::NonText; 9]);
+  assert_eq!(apca::minimum_font_sizes(-7.0), [FontUse::Prohibited; 9]);
+}
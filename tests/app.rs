@@ -1,7 +1,31 @@
 extern crate color_picker;
 
+mod alpha;
+mod apca;
+mod ase;
+mod color_difference;
+mod color_scale;
+mod color_url;
+mod contrast;
+mod css_color;
+mod cvd;
+mod gpl;
+mod gradient;
+mod harmony;
+mod history;
+mod jasc;
+mod mix;
+mod named_color;
+mod paint_net;
+mod palette;
+mod palette_db;
+mod parse_color;
+mod round_trip;
 mod validate_cmyk;
 mod validate_hex;
 mod validate_hsl;
 mod validate_hsv;
+mod validate_lab;
+mod validate_oklch;
 mod validate_rgb;
+mod xyz;
@@ -0,0 +1,151 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette::ase::{self, AseError};
+use color_picker::libs::palette::{Swatch, SwatchGroup};
+
+fn swatch(name: &str, hex: &str) -> Swatch {
+  Swatch {
+    name: String::from(name),
+    color: Color::from_hex(String::from(hex)).unwrap(),
+  }
+}
+
+fn header(block_count: u32) -> Vec<u8> {
+  let mut bytes = b"ASEF".to_vec();
+  bytes.extend_from_slice(&[0, 1, 0, 0]);
+  bytes.extend_from_slice(&block_count.to_be_bytes());
+  bytes
+}
+
+// color entry with a one letter name
+fn color_block(model: &[u8; 4], values: &[f32]) -> Vec<u8> {
+  let mut content = vec![0, 2, 0, b'A', 0, 0];
+  content.extend_from_slice(model);
+  for value in values {
+    content.extend_from_slice(&value.to_be_bytes());
+  }
+  content.extend_from_slice(&[0, 2]);
+
+  let mut bytes = vec![0, 1];
+  bytes.extend_from_slice(&(content.len() as u32).to_be_bytes());
+  bytes.extend_from_slice(&content);
+  bytes
+}
+
+fn read_hex(bytes: &[u8]) -> Vec<String> {
+  ase::read(bytes, "Saved").unwrap()[0]
+    .swatches
+    .iter()
+    .map(|swatch| swatch.color.hex_value())
+    .collect()
+}
+
+#[test]
+fn should_write_and_read_groups() {
+  let groups = vec![
+    SwatchGroup {
+      name: String::from("Brand"),
+      swatches: vec![swatch("Rot ä", "#e30613"), swatch("Sand", "#f2e3c6")],
+    },
+    SwatchGroup {
+      name: String::from("Empty"),
+      swatches: Vec::new(),
+    },
+  ];
+
+  let read = ase::read(&ase::write(&groups), "Saved").unwrap();
+
+  assert_eq!(read.len(), 2);
+  assert_eq!(read[0].name, "Brand");
+  assert_eq!(read[1].name, "Empty");
+  assert!(read[1].swatches.is_empty());
+
+  let swatches: Vec<(String, String)> = read[0]
+    .swatches
+    .iter()
+    .map(|swatch| (swatch.name.clone(), swatch.color.hex_value()))
+    .collect();
+  assert_eq!(
+    swatches,
+    vec![
+      (String::from("Rot ä"), String::from("#e30613")),
+      (String::from("Sand"), String::from("#f2e3c6"))
+    ]
+  );
+}
+
+#[test]
+fn should_read_other_color_models() {
+  let mut bytes = header(4);
+  bytes.extend(color_block(b"RGB ", &[1.0, 0.5, 0.0]));
+  bytes.extend(color_block(b"CMYK", &[0.0, 1.0, 1.0, 0.2]));
+  bytes.extend(color_block(b"LAB ", &[0.5, 0.0, 0.0]));
+  bytes.extend(color_block(b"Gray", &[0.25]));
+
+  assert_eq!(
+    read_hex(&bytes),
+    vec!["#ff8000", "#cc0000", "#777777", "#404040"]
+  );
+}
+
+#[test]
+fn should_put_swatches_without_group_into_default_group() {
+  let mut bytes = header(1);
+  bytes.extend(color_block(b"Gray", &[1.0]));
+
+  let groups = ase::read(&bytes, "Saved").unwrap();
+
+  assert_eq!(groups.len(), 1);
+  assert_eq!(groups[0].name, "Saved");
+  assert_eq!(groups[0].swatches[0].name, "A");
+}
+
+#[test]
+fn should_reject_invalid_files() {
+  assert_eq!(
+    ase::read(b"GPL palette", "Saved").err(),
+    Some(AseError::InvalidSignature)
+  );
+
+  let mut bytes = header(1);
+  bytes.extend(color_block(b"RGB ", &[1.0, 0.5, 0.0]));
+  bytes.truncate(bytes.len() - 4);
+  assert_eq!(
+    ase::read(&bytes, "Saved").err(),
+    Some(AseError::UnexpectedEnd)
+  );
+
+  let mut bytes = header(1);
+  bytes.extend(color_block(b"HSB ", &[1.0, 0.5, 0.0]));
+  assert_eq!(
+    ase::read(&bytes, "Saved").err(),
+    Some(AseError::UnknownColorModel(String::from("HSB ")))
+  );
+}
+
+#[test]
+fn should_reject_block_lengths_past_the_end() {
+  let mut bytes = header(1);
+  bytes.extend_from_slice(&[0, 1]);
+  bytes.extend_from_slice(&u32::MAX.to_be_bytes());
+  bytes.extend_from_slice(&[0, 2, 0, b'A']);
+
+  assert_eq!(
+    ase::read(&bytes, "Saved").unwrap_err(),
+    AseError::UnexpectedEnd
+  );
+}
+
+#[test]
+fn should_cut_names_longer_than_the_length_field() {
+  let name = "a".repeat(70_000);
+  let groups = vec![SwatchGroup {
+    name: name.clone(),
+    swatches: vec![swatch(&name, "#e30613")],
+  }];
+
+  let read = ase::read(&ase::write(&groups), "Saved").unwrap();
+
+  assert_eq!(read[0].name.len(), 65_534);
+  assert_eq!(read[0].swatches[0].name.len(), 65_534);
+  assert_eq!(read[0].swatches[0].color.hex_value(), "#e30613");
+}
@@ -0,0 +1,53 @@
+use color_picker::libs::color_scale::{self, ScaleKind};
+use color_picker::libs::color_transform::Color;
+
+fn hex_values(colors: Vec<Color>) -> Vec<String> {
+  colors.iter().map(|color| color.hex_value()).collect()
+}
+
+#[test]
+fn should_mix_toward_white_black_and_gray() {
+  let red = Color::from_hex(String::from("#ff0000")).unwrap();
+
+  assert_eq!(
+    hex_values(color_scale::scale(&red, ScaleKind::Tint, 4)),
+    vec!["#ff0000", "#ff4040", "#ff8080", "#ffbfbf"]
+  );
+  assert_eq!(
+    hex_values(color_scale::scale(&red, ScaleKind::Shade, 4)),
+    vec!["#ff0000", "#bf0000", "#800000", "#400000"]
+  );
+  assert_eq!(
+    hex_values(color_scale::scale(&red, ScaleKind::Tone, 2)),
+    vec!["#ff0000", "#bf4040"]
+  );
+}
+
+#[test]
+fn should_step_lightness_evenly_in_oklch() {
+  let blue = Color::from_hex(String::from("#1e90ff")).unwrap();
+  let colors = color_scale::scale(&blue, ScaleKind::Lightness, 10);
+
+  assert_eq!(colors.len(), 10);
+
+  let lightness: Vec<f64> = colors
+    .iter()
+    .map(|color| color.to_oklch().get_lightness())
+    .collect();
+
+  assert!((lightness[0] - 0.97).abs() < 0.01);
+  assert!((lightness[9] - 0.2).abs() < 0.01);
+
+  for pair in lightness.windows(2) {
+    assert!(pair[0] > pair[1]);
+  }
+}
+
+#[test]
+fn should_keep_alpha() {
+  let color = Color::from_rgba_values(0.0, 128.0, 255.0, 0.5);
+  let colors = color_scale::scale(&color, ScaleKind::Shade, 3);
+
+  assert!(colors.iter().all(|color| color.get_alpha() == 0.5));
+  assert!(color_scale::scale(&color, ScaleKind::Tint, 0).is_empty());
+}
@@ -0,0 +1,69 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::color_url;
+
+fn url_hex(search: &str, hash: &str) -> Option<String> {
+  color_url::parse_url_color(search, hash).map(|color| color.hex_value())
+}
+
+#[test]
+fn should_read_color_from_query() {
+  assert_eq!(
+    url_hex("?color=%23ff8800", ""),
+    Some(String::from("#ff8800"))
+  );
+  assert_eq!(
+    url_hex("?lang=en&color=rgb(255%2C+136%2C+0)", ""),
+    Some(String::from("#ff8800"))
+  );
+  assert_eq!(url_hex("?color=nope", ""), None);
+  assert_eq!(url_hex("", ""), None);
+}
+
+#[test]
+fn should_read_color_from_hash() {
+  assert_eq!(url_hex("", "#hsl=200,50,50"), Some(String::from("#4095bf")));
+  assert_eq!(
+    url_hex("", "#hsv=30,100%,100%"),
+    Some(String::from("#ff8000"))
+  );
+  assert_eq!(
+    url_hex("", "#rgb=255,136,0,0.5"),
+    Some(String::from("#ff880080"))
+  );
+  assert_eq!(url_hex("", "#hex=ff8800"), Some(String::from("#ff8800")));
+}
+
+#[test]
+fn should_prefer_hash_over_query() {
+  assert_eq!(
+    url_hex("?color=%23000000", "#hex=ffffff"),
+    Some(String::from("#ffffff"))
+  );
+  assert_eq!(
+    url_hex("?color=%23000000", "#hsl=500,50,50"),
+    Some(String::from("#000000"))
+  );
+}
+
+#[test]
+fn should_write_color_to_hash() {
+  let color = Color::parse("#ff8800").unwrap();
+  let hash = color_url::url_hash(&color);
+
+  assert_eq!(hash, "#hex=ff8800");
+  assert_eq!(url_hex("", &hash), Some(String::from("#ff8800")));
+}
+
+#[test]
+fn should_keep_hue_of_grays_in_hash() {
+  let gray = Color::from_hsv_values(200.0, 0.0, 49.8);
+  let hash = color_url::url_hash(&gray);
+
+  assert_eq!(hash, "#hsv=200,0,49.8");
+  let color = color_url::parse_url_color("", &hash).unwrap();
+  assert_eq!(color.get_hue(), 200.0);
+  assert_eq!(color.hex_value(), "#7f7f7f");
+
+  let black = Color::from_hsv_values(120.0, 40.0, 0.0);
+  assert_eq!(color_url::url_hash(&black), "#hsv=120,40,0");
+}
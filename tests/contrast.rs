@@ -0,0 +1,50 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::contrast::{self, WcagCompliance};
+
+#[test]
+fn should_calculate_relative_luminance() {
+  let white = Color::from_hex(String::from("#ffffff")).unwrap();
+  let black = Color::from_hex(String::from("#000000")).unwrap();
+
+  assert_eq!(white.relative_luminance(), 1.0);
+  assert_eq!(black.relative_luminance(), 0.0);
+}
+
+#[test]
+fn should_calculate_contrast_ratio() {
+  let white = Color::from_hex(String::from("#ffffff")).unwrap();
+  let black = Color::from_hex(String::from("#000000")).unwrap();
+  let grey = Color::from_hex(String::from("#767676")).unwrap();
+
+  assert_eq!(
+    contrast::format_ratio(contrast::contrast_ratio(&white, &black)),
+    "21:1"
+  );
+  assert_eq!(
+    contrast::format_ratio(contrast::contrast_ratio(&grey, &white)),
+    "4.54:1"
+  );
+  assert_eq!(
+    contrast::format_ratio(contrast::contrast_ratio(&white, &grey)),
+    "4.54:1"
+  );
+}
+
+#[test]
+fn should_check_wcag_levels() {
+  let compliance = WcagCompliance::from_ratio(4.54);
+  assert_eq!(
+    compliance,
+    WcagCompliance {
+      aa_normal: true,
+      aa_large: true,
+      aaa_normal: false,
+      aaa_large: true,
+    }
+  );
+
+  let white = Color::from_hex(String::from("#ffffff")).unwrap();
+  let yellow = Color::from_hex(String::from("#ffff00")).unwrap();
+  let compliance = contrast::compliance(&white, &yellow);
+  assert!(!compliance.aa_large);
+}
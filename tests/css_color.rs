@@ -0,0 +1,69 @@
+use color_picker::libs::color_transform::parse_error::{ColorComponent, ParseColorError};
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::css_color::{self, CssFormat};
+
+#[test]
+fn should_parse_modern_rgb_syntax() {
+  let color = css_color::parse("rgb(12 34 56 / 50%)").unwrap();
+  assert_eq!(color.rgb_value(), "12, 34, 56, 0.5");
+}
+
+#[test]
+fn should_parse_hsl_with_angle_units() {
+  let color = css_color::parse("hsl(210deg 40% 50%)").unwrap();
+  assert_eq!(color.rgb_value(), "77, 128, 179");
+
+  let color = css_color::parse("hsl(0.5turn 100% 50%)").unwrap();
+  assert_eq!(color.hex_value(), "#00ffff");
+}
+
+#[test]
+fn should_parse_short_hex_with_alpha() {
+  let color = css_color::parse("#abcd").unwrap();
+  assert_eq!(color.hex_value(), "#aabbccdd");
+}
+
+#[test]
+fn should_parse_hwb() {
+  let color = css_color::parse("hwb(120 20% 30%)").unwrap();
+  assert_eq!(color.rgb_value(), "51, 179, 51");
+}
+
+#[test]
+fn should_parse_lab_and_oklch() {
+  let color = css_color::parse("lab(29.2345% 39.3825 20.0664)").unwrap();
+  assert_eq!(color.hex_value(), "#7d2329");
+
+  let color = css_color::parse("oklch(62.8% 0.2577 29.23)").unwrap();
+  assert_eq!(color.hex_value(), "#ff0000");
+}
+
+#[test]
+fn should_clip_wide_gamut_colors() {
+  let color = css_color::parse("color(display-p3 1 0 0)").unwrap();
+  assert_eq!(color.rgb_value(), "255, 0, 0");
+}
+
+#[test]
+fn should_report_css_errors() {
+  let error = css_color::parse("lab(50 x 3)").err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::Malformed {
+      component: ColorComponent::GreenRed,
+      value: String::from("x")
+    })
+  );
+}
+
+#[test]
+fn should_serialize_color_as_css() {
+  let color = Color::from_hsv_values(120.0, 50.0, 80.0);
+
+  assert_eq!(color.to_css_string(CssFormat::Hex), "#66cc66");
+  assert_eq!(color.to_css_string(CssFormat::Rgb), "rgb(102, 204, 102)");
+  assert_eq!(color.to_css_string(CssFormat::Hwb), "hwb(120 40% 20%)");
+
+  let parsed = css_color::parse(&color.to_css_string(CssFormat::Hwb)).unwrap();
+  assert_eq!(parsed.hex_value(), "#66cc66");
+}
@@ -0,0 +1,104 @@
+use color_picker::libs::color_difference::{self, DeltaE};
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::cvd::{self, CvdModel, Deficiency, Simulation, CVD_MODELS, DEFICIENCIES};
+
+fn hex(value: &str) -> Color {
+  Color::from_hex(String::from(value)).unwrap()
+}
+
+#[test]
+fn should_keep_color_without_severity() {
+  let color = hex("#6495ed");
+
+  for model in CVD_MODELS {
+    for deficiency in DEFICIENCIES {
+      assert_eq!(
+        cvd::simulate(&color, deficiency, 0.0, model).hex_value(),
+        "#6495ed"
+      );
+    }
+  }
+}
+
+#[test]
+fn should_keep_grays() {
+  for value in ["#ffffff", "#808080", "#000000"] {
+    for model in CVD_MODELS {
+      for deficiency in DEFICIENCIES {
+        assert_eq!(
+          cvd::simulate(&hex(value), deficiency, 1.0, model).hex_value(),
+          value
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn should_simulate_dichromacy() {
+  let red = hex("#ff0000");
+  let green = hex("#00ff00");
+  let blue = hex("#0000ff");
+
+  assert_eq!(
+    cvd::simulate(&red, Deficiency::Protan, 1.0, CvdModel::Machado).hex_value(),
+    "#6d5f00"
+  );
+  assert_eq!(
+    cvd::simulate(&red, Deficiency::Deutan, 1.0, CvdModel::Machado).hex_value(),
+    "#a39000"
+  );
+  assert_eq!(
+    cvd::simulate(&green, Deficiency::Deutan, 1.0, CvdModel::Brettel).hex_value(),
+    "#f2d12e"
+  );
+  assert_eq!(
+    cvd::simulate(&blue, Deficiency::Tritan, 1.0, CvdModel::Brettel).hex_value(),
+    "#006087"
+  );
+}
+
+#[test]
+fn should_interpolate_anomalous_trichromacy() {
+  let red = hex("#ff0000");
+
+  assert_eq!(
+    cvd::simulate(&red, Deficiency::Deutan, 0.55, CvdModel::Machado).hex_value(),
+    "#bf7a00"
+  );
+  assert_eq!(
+    cvd::simulate(&red, Deficiency::Protan, 0.55, CvdModel::Brettel).hex_value(),
+    "#c04408"
+  );
+}
+
+#[test]
+fn should_make_red_and_green_harder_to_tell_apart() {
+  let red = hex("#ff0000");
+  let green = hex("#00ff00");
+  let original = color_difference::delta_e(&red, &green, DeltaE::Ciede2000);
+
+  for model in CVD_MODELS {
+    let mut previous = original;
+
+    for severity in [0.3, 0.6, 1.0] {
+      let simulation = Simulation::new(Deficiency::Deutan, severity, model);
+      let difference = color_difference::delta_e(
+        &simulation.apply(&red),
+        &simulation.apply(&green),
+        DeltaE::Ciede2000,
+      );
+
+      assert!(difference < previous);
+      previous = difference;
+    }
+  }
+}
+
+#[test]
+fn should_keep_alpha() {
+  let color = Color::parse("rgba(255, 0, 0, 0.5)").unwrap();
+  let simulated = cvd::simulate(&color, Deficiency::Protan, 1.0, CvdModel::Machado);
+
+  assert_eq!(simulated.get_alpha(), 0.5);
+}
@@ -0,0 +1,58 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette::{gpl, PaletteFileError, Swatch, SwatchGroup};
+
+fn swatch(name: &str, hex: &str) -> Swatch {
+  Swatch {
+    name: String::from(name),
+    color: Color::from_hex(String::from(hex)).unwrap(),
+  }
+}
+
+fn names_and_hex(group: &SwatchGroup) -> Vec<(String, String)> {
+  group
+    .swatches
+    .iter()
+    .map(|swatch| (swatch.name.clone(), swatch.color.hex_value()))
+    .collect()
+}
+
+#[test]
+fn should_write_and_read_palette() {
+  let group = SwatchGroup {
+    name: String::from("Brand"),
+    swatches: vec![swatch("Brand red", "#e30613"), swatch("Sand", "#f2e3c6")],
+  };
+
+  let read = gpl::read(&gpl::write(&group), "Saved").unwrap();
+
+  assert_eq!(read.name, "Brand");
+  assert_eq!(names_and_hex(&read), names_and_hex(&group));
+}
+
+#[test]
+fn should_read_gimp_palette() {
+  let source = "GIMP Palette\r\nName: Krita colors\r\nColumns: 4\r\n#\r\n# comment\r\n  0   0 255\tBlue\r\n255 255 255\r\n";
+  let group = gpl::read(source, "Saved").unwrap();
+
+  assert_eq!(group.name, "Krita colors");
+  assert_eq!(
+    names_and_hex(&group),
+    vec![
+      (String::from("Blue"), String::from("#0000ff")),
+      (String::from("#ffffff"), String::from("#ffffff"))
+    ]
+  );
+  assert_eq!(gpl::read("GIMP Palette\n", "Saved").unwrap().name, "Saved");
+}
+
+#[test]
+fn should_reject_invalid_gimp_palette() {
+  assert_eq!(
+    gpl::read("JASC-PAL\n0100\n0\n", "Saved").err(),
+    Some(PaletteFileError::MissingHeader)
+  );
+  assert_eq!(
+    gpl::read("GIMP Palette\nName: Test\n255 0 256 Red\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColor { line: 3 })
+  );
+}
@@ -0,0 +1,77 @@
+use color_picker::libs::color_transform::interpolation::{HueInterpolation, InterpolationSpace};
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::gradient::{Gradient, GradientShape};
+
+fn color(value: &str) -> Color {
+  Color::parse(value).unwrap()
+}
+
+fn red_to_blue() -> Gradient {
+  let mut gradient = Gradient::new(color("#ff0000"), color("#0000ff"));
+  gradient.set_space(InterpolationSpace::Srgb);
+  gradient
+}
+
+#[test]
+fn should_keep_stops_sorted() {
+  let mut gradient = red_to_blue();
+
+  assert_eq!(gradient.add_stop(0.5), 1);
+  assert_eq!(gradient.get_stops()[1].color.hex_value(), "#800080");
+
+  assert_eq!(gradient.move_stop(1, 1.5), Some(2));
+  assert_eq!(gradient.get_stops()[2].position, 1.0);
+  assert_eq!(gradient.move_stop(3, 0.5), None);
+  assert_eq!(gradient.get_stops().len(), 3);
+
+  assert!(gradient.remove_stop(0));
+  assert!(!gradient.remove_stop(0));
+  assert_eq!(gradient.get_stops().len(), 2);
+}
+
+#[test]
+fn should_interpolate_between_stops() {
+  let mut gradient = red_to_blue();
+  let index = gradient.add_stop(0.5);
+  gradient.set_stop_color(index, color("#00ff00"));
+
+  assert_eq!(gradient.color_at(-1.0).hex_value(), "#ff0000");
+  assert_eq!(gradient.color_at(0.25).hex_value(), "#808000");
+  assert_eq!(gradient.color_at(0.75).hex_value(), "#008080");
+  assert_eq!(gradient.color_at(2.0).hex_value(), "#0000ff");
+}
+
+#[test]
+fn should_export_css_gradients() {
+  let mut gradient = red_to_blue();
+
+  assert_eq!(
+    gradient.to_css(GradientShape::Linear),
+    "linear-gradient(90deg in srgb, #ff0000 0%, #0000ff 100%)"
+  );
+
+  gradient.set_space(InterpolationSpace::Oklch(HueInterpolation::Longer));
+  gradient.set_angle(45.0);
+  assert_eq!(
+    gradient.to_css(GradientShape::Conic),
+    "conic-gradient(from 45deg in oklch longer hue, #ff0000 0%, #0000ff 100%)"
+  );
+
+  gradient.set_space(InterpolationSpace::Oklab);
+  assert_eq!(
+    gradient.to_css(GradientShape::Radial),
+    "radial-gradient(circle in oklab, #ff0000 0%, #0000ff 100%)"
+  );
+}
+
+#[test]
+fn should_bake_hsv_stops_into_css() {
+  let mut gradient = red_to_blue();
+  gradient.set_space(InterpolationSpace::Hsv(HueInterpolation::Shorter));
+
+  let css = gradient.to_css(GradientShape::Linear);
+
+  assert!(css.starts_with("linear-gradient(90deg, #ff0000 0%, "));
+  assert!(css.contains("#ff00ff 50%"));
+  assert!(css.ends_with("#0000ff 100%)"));
+}
@@ -0,0 +1,54 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::harmony::{self, Harmony, HarmonySpace};
+
+fn hex_values(colors: Vec<Color>) -> Vec<String> {
+  colors.iter().map(|color| color.hex_value()).collect()
+}
+
+#[test]
+fn should_rotate_hue_in_hsv() {
+  let red = Color::from_hex(String::from("#ff0000")).unwrap();
+
+  assert_eq!(
+    hex_values(harmony::harmony(
+      &red,
+      Harmony::Complementary,
+      HarmonySpace::Hsv
+    )),
+    vec!["#ff0000", "#00ffff"]
+  );
+  assert_eq!(
+    hex_values(harmony::harmony(&red, Harmony::Triadic, HarmonySpace::Hsv)),
+    vec!["#ff0000", "#00ff00", "#0000ff"]
+  );
+  assert_eq!(
+    hex_values(harmony::harmony(
+      &red,
+      Harmony::Analogous,
+      HarmonySpace::Hsv
+    )),
+    vec!["#ff0000", "#ff0080", "#ff8000"]
+  );
+  assert_eq!(
+    harmony::harmony(&red, Harmony::Tetradic, HarmonySpace::Hsv).len(),
+    4
+  );
+}
+
+#[test]
+fn should_keep_saturation_value_and_alpha() {
+  let color = Color::from_hsva_values(200.0, 40.0, 60.0, 0.5);
+  let complement = harmony::rotate(&color, 180.0, HarmonySpace::Hsv);
+
+  assert_eq!(complement.hsv_value(), "20°, 40%, 60%, 0.5");
+}
+
+#[test]
+fn should_rotate_hue_in_oklch() {
+  let color = Color::from_oklch(String::from("60% 0.1 30")).unwrap();
+  let colors = harmony::harmony(&color, Harmony::SplitComplementary, HarmonySpace::Oklch);
+
+  assert_eq!(colors.len(), 3);
+  assert_eq!(colors[1].oklch_value(), "60% 0.1 180");
+  assert_eq!(colors[2].oklch_value(), "60% 0.1 240");
+}
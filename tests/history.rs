@@ -0,0 +1,57 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::history::History;
+
+#[test]
+fn should_undo_and_redo_recorded_values() {
+  let mut history = History::new(1, 10);
+  history.record(2);
+  history.record(3);
+
+  assert_eq!(history.undo(), Some(&2));
+  assert_eq!(history.undo(), Some(&1));
+  assert_eq!(history.undo(), None);
+  assert_eq!(history.redo(), Some(&2));
+
+  history.record(4);
+  assert!(!history.can_redo());
+  assert_eq!(history.undo(), Some(&2));
+  assert_eq!(*history.get_current(), 2);
+}
+
+#[test]
+fn should_coalesce_drag_sequences() {
+  let mut history = History::new(0, 10);
+
+  history.begin_coalescing();
+  for value in 1..=50 {
+    history.record(value);
+  }
+  history.end_coalescing();
+  history.record(100);
+
+  assert_eq!(history.undo(), Some(&50));
+  assert_eq!(history.undo(), Some(&0));
+  assert!(!history.can_undo());
+}
+
+#[test]
+fn should_drop_oldest_values_above_limit() {
+  let mut history = History::new(0, 3);
+  for value in 1..=5 {
+    history.record(value);
+  }
+
+  assert_eq!(history.undo(), Some(&4));
+  assert_eq!(history.undo(), Some(&3));
+  assert_eq!(history.undo(), Some(&2));
+  assert_eq!(history.undo(), None);
+}
+
+#[test]
+fn should_keep_color_history() {
+  let mut history = History::new(Color::parse("#ff0000").unwrap(), 10);
+  history.record(Color::parse("#00ff00").unwrap());
+
+  assert_eq!(history.undo().unwrap().hex_value(), "#ff0000");
+  assert_eq!(history.redo().unwrap().hex_value(), "#00ff00");
+}
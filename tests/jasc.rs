@@ -0,0 +1,52 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette::{jasc, PaletteFileError, Swatch, SwatchGroup};
+
+fn hex_values(group: &SwatchGroup) -> Vec<String> {
+  group
+    .swatches
+    .iter()
+    .map(|swatch| swatch.color.hex_value())
+    .collect()
+}
+
+#[test]
+fn should_write_and_read_palette() {
+  let group = SwatchGroup {
+    name: String::from("Brand"),
+    swatches: ["#e30613", "#f2e3c6", "#000000"]
+      .iter()
+      .map(|hex| Swatch {
+        name: String::from("Color"),
+        color: Color::from_hex(hex.to_string()).unwrap(),
+      })
+      .collect(),
+  };
+
+  let source = jasc::write(&group);
+  assert!(source.starts_with("JASC-PAL\r\n0100\r\n3\r\n227 6 19\r\n"));
+
+  let read = jasc::read(&source, "Saved").unwrap();
+  assert_eq!(read.name, "Saved");
+  assert_eq!(read.swatches[0].name, "#e30613");
+  assert_eq!(hex_values(&read), hex_values(&group));
+}
+
+#[test]
+fn should_reject_invalid_jasc_palette() {
+  assert_eq!(
+    jasc::read("GIMP Palette\n", "Saved").err(),
+    Some(PaletteFileError::MissingHeader)
+  );
+  assert_eq!(
+    jasc::read("JASC-PAL\n0100\nmany\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColorCount { line: 3 })
+  );
+  assert_eq!(
+    jasc::read("JASC-PAL\n0100\n2\n255 0 0\n", "Saved").err(),
+    Some(PaletteFileError::UnexpectedEnd)
+  );
+  assert_eq!(
+    jasc::read("JASC-PAL\n0100\n1\n255 0 0 Red\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColor { line: 4 })
+  );
+}
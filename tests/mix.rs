@@ -0,0 +1,107 @@
+use color_picker::libs::color_transform::interpolation::{HueInterpolation, InterpolationSpace};
+use color_picker::libs::color_transform::Color;
+
+fn color(value: &str) -> Color {
+  Color::parse(value).unwrap()
+}
+
+#[test]
+fn should_mix_in_rectangular_spaces() {
+  let red = color("#ff0000");
+  let blue = color("#0000ff");
+
+  assert_eq!(
+    red.mix(&blue, 0.5, InterpolationSpace::Srgb).hex_value(),
+    "#800080"
+  );
+  assert_eq!(
+    red
+      .mix(&blue, 0.5, InterpolationSpace::LinearRgb)
+      .hex_value(),
+    "#bc00bc"
+  );
+  assert_eq!(
+    red.mix(&blue, 0.0, InterpolationSpace::Oklab).hex_value(),
+    "#ff0000"
+  );
+  assert_eq!(
+    red.mix(&blue, 1.0, InterpolationSpace::Lab).hex_value(),
+    "#0000ff"
+  );
+}
+
+#[test]
+fn should_take_shorter_or_longer_hue_path() {
+  let red = color("#ff0000");
+  let blue = color("#0000ff");
+
+  assert_eq!(
+    red
+      .mix(
+        &blue,
+        0.5,
+        InterpolationSpace::Hsl(HueInterpolation::Shorter)
+      )
+      .hex_value(),
+    "#ff00ff"
+  );
+  assert_eq!(
+    red
+      .mix(
+        &blue,
+        0.5,
+        InterpolationSpace::Hsv(HueInterpolation::Longer)
+      )
+      .hex_value(),
+    "#00ff00"
+  );
+
+  assert_eq!(
+    HueInterpolation::Shorter.interpolate(Some(350.0), Some(10.0), 0.5),
+    0.0
+  );
+  assert_eq!(
+    HueInterpolation::Longer.interpolate(Some(350.0), Some(10.0), 0.5),
+    180.0
+  );
+  assert_eq!(
+    HueInterpolation::Increasing.interpolate(Some(350.0), Some(10.0), 0.5),
+    0.0
+  );
+  assert_eq!(
+    HueInterpolation::Decreasing.interpolate(Some(350.0), Some(10.0), 0.5),
+    180.0
+  );
+}
+
+#[test]
+fn should_use_hue_of_chromatic_color_with_grey() {
+  let white = color("#ffffff");
+  let blue = color("#6495ed");
+
+  let mixed = white.mix(
+    &blue,
+    0.5,
+    InterpolationSpace::Oklch(HueInterpolation::Shorter),
+  );
+  let hue = mixed.to_oklch().get_hue();
+
+  assert!((hue - blue.to_oklch().get_hue()).abs() < 0.5);
+  assert_eq!(
+    HueInterpolation::Shorter.interpolate(None, Some(120.0), 0.5),
+    120.0
+  );
+}
+
+#[test]
+fn should_mix_premultiplied_alpha() {
+  let red = color("#ff0000");
+  let transparent = color("rgba(0, 0, 255, 0)");
+
+  assert_eq!(
+    red
+      .mix(&transparent, 0.5, InterpolationSpace::Srgb)
+      .hex_value(),
+    "#ff000080"
+  );
+}
@@ -0,0 +1,48 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::css_color;
+use color_picker::libs::named_color::{self, NAMED_COLORS};
+
+#[test]
+fn should_find_named_colors() {
+  assert_eq!(
+    named_color::find("rebeccapurple").unwrap().hex_value(),
+    "#663399"
+  );
+  assert_eq!(
+    named_color::find(" Tomato ").unwrap().hex_value(),
+    "#ff6347"
+  );
+  assert_eq!(named_color::find("GREY").unwrap().hex_value(), "#808080");
+  assert_eq!(named_color::find("transparent").unwrap().get_alpha(), 0.0);
+  assert!(named_color::find("tomatoes").is_none());
+}
+
+#[test]
+fn should_keep_the_table_sorted() {
+  for pair in NAMED_COLORS.windows(2) {
+    assert!(pair[0].0 < pair[1].0);
+  }
+}
+
+#[test]
+fn should_parse_named_colors() {
+  assert_eq!(Color::parse("tomato").unwrap().hex_value(), "#ff6347");
+  assert_eq!(
+    css_color::parse("CornflowerBlue").unwrap().hex_value(),
+    "#6495ed"
+  );
+}
+
+#[test]
+fn should_find_nearest_name() {
+  let nearest = named_color::nearest(&Color::from_hex(String::from("#ff6347")).unwrap());
+  assert_eq!(nearest.name, "tomato");
+  assert!(nearest.delta_e < 1e-6);
+
+  let nearest = named_color::nearest(&Color::from_hex(String::from("#00fffe")).unwrap());
+  assert_eq!(nearest.name, "aqua");
+
+  let nearest = named_color::nearest(&Color::from_hex(String::from("#fe6448")).unwrap());
+  assert_eq!(nearest.name, "tomato");
+  assert!(nearest.delta_e > 0.0 && nearest.delta_e < 1.0);
+}
@@ -0,0 +1,72 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette::{paint_net, PaletteFileError, Swatch, SwatchGroup};
+
+#[test]
+fn should_write_and_read_palette_with_alpha() {
+  let group = SwatchGroup {
+    name: String::from("Brand"),
+    swatches: ["#e30613", "rgba(242, 227, 198, 0.5)"]
+      .iter()
+      .map(|value| Swatch {
+        name: String::from("Color"),
+        color: Color::parse(value).unwrap(),
+      })
+      .collect(),
+  };
+
+  let (source, dropped) = paint_net::write(&group);
+  assert_eq!(dropped, 0);
+  assert!(source.starts_with("; paint.net Palette File\r\n"));
+  assert!(source.ends_with("FFE30613\r\n80F2E3C6\r\n"));
+
+  let read = paint_net::read(&source, "Saved").unwrap();
+  let colors: Vec<(String, f32)> = read
+    .swatches
+    .iter()
+    .map(|swatch| (swatch.name.clone(), swatch.color.get_alpha()))
+    .collect();
+
+  assert_eq!(read.name, "Saved");
+  assert_eq!(
+    colors,
+    vec![
+      (String::from("#e30613"), 1.0),
+      (String::from("#f2e3c680"), 128.0 / 255.0)
+    ]
+  );
+}
+
+#[test]
+fn should_reject_invalid_paint_net_palette() {
+  assert_eq!(
+    paint_net::read("; comment\nFF0000\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColor { line: 2 })
+  );
+  assert_eq!(
+    paint_net::read("FFGG0000\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColor { line: 1 })
+  );
+  assert_eq!(
+    paint_net::read("+FF00000\n", "Saved").err(),
+    Some(PaletteFileError::InvalidColor { line: 1 })
+  );
+}
+
+#[test]
+fn should_write_up_to_96_colors() {
+  let group = SwatchGroup {
+    name: String::from("Reds"),
+    swatches: (0..100)
+      .map(|index| Swatch {
+        name: String::new(),
+        color: Color::from_srgb_values(f64::from(index) / 99.0, 0.0, 0.0, 1.0),
+      })
+      .collect(),
+  };
+
+  let (source, dropped) = paint_net::write(&group);
+  assert_eq!(dropped, 4);
+
+  let read = paint_net::read(&source, "Saved").unwrap();
+  assert_eq!(read.swatches.len(), paint_net::MAX_COLORS);
+}
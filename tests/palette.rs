@@ -0,0 +1,92 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette::storage::MemoryStorage;
+use color_picker::libs::palette::Palette;
+
+fn color(value: &str) -> Color {
+  Color::parse(value).unwrap()
+}
+
+fn swatch_names(palette: &Palette, group: usize) -> Vec<String> {
+  palette.get_groups()[group]
+    .swatches
+    .iter()
+    .map(|swatch| swatch.name.clone())
+    .collect()
+}
+
+#[test]
+fn should_start_with_default_group() {
+  let palette = Palette::load(Box::new(MemoryStorage::new()), "Saved");
+
+  assert_eq!(palette.get_groups().len(), 1);
+  assert_eq!(palette.get_groups()[0].name, "Saved");
+  assert!(palette.get_groups()[0].swatches.is_empty());
+}
+
+#[test]
+fn should_add_remove_and_reorder_swatches() {
+  let mut palette = Palette::load(Box::new(MemoryStorage::new()), "Saved");
+
+  assert_eq!(
+    palette.add_swatch(0, String::from("red"), color("#ff0000")),
+    Some(0)
+  );
+  assert_eq!(
+    palette.add_swatch(0, String::from("green"), color("#00ff00")),
+    Some(1)
+  );
+  assert_eq!(
+    palette.add_swatch(0, String::from("blue"), color("#0000ff")),
+    Some(2)
+  );
+  assert_eq!(
+    palette.add_swatch(1, String::from("none"), color("#000000")),
+    None
+  );
+
+  assert!(palette.move_swatch(0, 2, 0));
+  assert_eq!(swatch_names(&palette, 0), vec!["blue", "red", "green"]);
+
+  assert!(palette.move_swatch(0, 0, 10));
+  assert_eq!(swatch_names(&palette, 0), vec!["red", "green", "blue"]);
+
+  assert!(palette.remove_swatch(0, 1));
+  assert!(!palette.remove_swatch(0, 5));
+  assert_eq!(swatch_names(&palette, 0), vec!["red", "blue"]);
+  assert_eq!(
+    palette.get_swatch(0, 1).unwrap().color.hex_value(),
+    "#0000ff"
+  );
+}
+
+#[test]
+fn should_manage_groups() {
+  let mut palette = Palette::load(Box::new(MemoryStorage::new()), "Saved");
+
+  assert_eq!(palette.add_group(String::from("Brand")), 1);
+  assert!(palette.rename_group(1, String::from("Brand colors")));
+  assert_eq!(palette.get_groups()[1].name, "Brand colors");
+
+  assert!(palette.remove_group(0));
+  assert!(!palette.remove_group(3));
+  assert_eq!(palette.get_groups().len(), 1);
+
+  assert!(!palette.remove_group(0));
+  assert_eq!(palette.get_groups()[0].name, "Brand colors");
+}
+
+#[test]
+fn should_persist_palette_in_storage() {
+  let storage = MemoryStorage::new();
+
+  let mut palette = Palette::load(Box::new(storage.clone()), "Saved");
+  palette.add_group(String::from("Brand"));
+  palette.add_swatch(1, String::from("accent"), color("#ff660080"));
+
+  let palette = Palette::load(Box::new(storage), "Saved");
+  let swatch = palette.get_swatch(1, 0).unwrap();
+
+  assert_eq!(palette.get_groups()[1].name, "Brand");
+  assert_eq!(swatch.name, "accent");
+  assert_eq!(swatch.color.hex_value(), "#ff660080");
+}
@@ -0,0 +1,76 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::palette_db::{BuiltinPalette, PaletteDb, PaletteDbError, BUILTIN_PALETTES};
+
+fn hex(value: &str) -> Color {
+  Color::from_hex(String::from(value)).unwrap()
+}
+
+#[test]
+fn should_parse_hex_and_lab_entries() {
+  let db = PaletteDb::parse(
+    "# corporate colors\n\nBrand red = #e30613\n  Brand white = lab(100, 0, 0)  \nA = B = #000\n",
+  )
+  .unwrap();
+  let entries = db.get_entries();
+
+  assert_eq!(entries.len(), 3);
+  assert_eq!(entries[0].name, "Brand red");
+  assert_eq!(entries[0].color.hex_value(), "#e30613");
+  assert_eq!(entries[1].name, "Brand white");
+  assert_eq!(entries[1].color.hex_value(), "#ffffff");
+  assert_eq!(entries[2].name, "A = B");
+  assert_eq!(db.find("brand RED").unwrap().color.hex_value(), "#e30613");
+}
+
+#[test]
+fn should_report_the_broken_line() {
+  assert_eq!(
+    PaletteDb::parse("Red = #f00\nGreen #0f0").err(),
+    Some(PaletteDbError::MissingSeparator { line: 2 })
+  );
+  assert_eq!(
+    PaletteDb::parse(" = #f00").err(),
+    Some(PaletteDbError::MissingName { line: 1 })
+  );
+  assert!(matches!(
+    PaletteDb::parse("Red = #f00\n\nGreen = green"),
+    Err(PaletteDbError::InvalidColor { line: 3, .. })
+  ));
+}
+
+#[test]
+fn should_find_closest_entries() {
+  let db = PaletteDb::parse("Black = #000000\nGrey = #808080\nWhite = #ffffff").unwrap();
+  let matches = db.closest(&hex("#707070"), 2);
+
+  assert_eq!(matches.len(), 2);
+  assert_eq!(matches[0].entry.name, "Grey");
+  assert_eq!(matches[1].entry.name, "Black");
+  assert!(matches[0].delta_e < matches[1].delta_e);
+}
+
+#[test]
+fn should_load_builtin_palettes() {
+  let sizes: Vec<usize> = BUILTIN_PALETTES
+    .iter()
+    .map(|palette| palette.load().get_entries().len())
+    .collect();
+  assert_eq!(sizes, vec![254, 242, 213]);
+
+  let material = BuiltinPalette::Material.load();
+  let closest = material.closest(&hex("#f44336"), 1);
+  assert_eq!(closest[0].entry.name, "Red 500");
+  assert!(closest[0].delta_e < 1e-6);
+
+  let tailwind = BuiltinPalette::Tailwind.load();
+  assert_eq!(
+    tailwind.closest(&hex("#3b82f5"), 1)[0].entry.name,
+    "blue-500"
+  );
+
+  let ral = BuiltinPalette::RalClassic.load();
+  assert_eq!(
+    ral.closest(&hex("#f7b501"), 1)[0].entry.name,
+    "RAL 1023 Traffic yellow"
+  );
+}
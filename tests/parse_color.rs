@@ -0,0 +1,130 @@
+use std::convert::TryFrom;
+
+use color_picker::libs::color_transform::cmyk_color::CMYK;
+use color_picker::libs::color_transform::hex_color::Hex;
+use color_picker::libs::color_transform::hsl_color::HSL;
+use color_picker::libs::color_transform::lab_color::Lab;
+use color_picker::libs::color_transform::parse_error::{ColorComponent, ParseColorError};
+use color_picker::libs::color_transform::rgb_color::RGB;
+use color_picker::libs::color_transform::Color;
+
+#[test]
+fn should_parse_rgb_string() {
+  let rgb = "12, 34, 56".parse::<RGB>().unwrap();
+  assert_eq!(rgb.to_string(), "12, 34, 56");
+}
+
+#[test]
+fn should_report_out_of_range_component() {
+  let error = "12, 256, 56".parse::<RGB>().err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::OutOfRange {
+      component: ColorComponent::Green,
+      value: 256.0
+    })
+  );
+}
+
+#[test]
+fn should_report_malformed_component() {
+  let error = CMYK::try_from("40%, 30, 20%, 10%").err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::Malformed {
+      component: ColorComponent::Magenta,
+      value: String::from("30")
+    })
+  );
+}
+
+#[test]
+fn should_allow_minus_sign_only_for_lab_axes() {
+  let error = "-0, 34, 56".parse::<RGB>().err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::Malformed {
+      component: ColorComponent::Red,
+      value: String::from("-0")
+    })
+  );
+  assert!(CMYK::try_from("40%, -0%, 20%, 10%").is_err());
+  assert!("50, -20.5, -0".parse::<Lab>().is_ok());
+}
+
+#[test]
+fn should_report_wrong_component_count() {
+  let error = HSL::try_from("300°, 50%").err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::ComponentCount {
+      expected: 3,
+      found: 2
+    })
+  );
+}
+
+#[test]
+fn should_report_malformed_hex_channel() {
+  let error = "#12g456".parse::<Hex>().err();
+  assert_eq!(
+    error,
+    Some(ParseColorError::Malformed {
+      component: ColorComponent::Green,
+      value: String::from("g4")
+    })
+  );
+}
+
+#[test]
+fn should_parse_color_notations() {
+  let color = Color::parse("hsl(300°, 50%, 20%)").unwrap();
+  assert_eq!(color.rgb_value(), "77, 26, 77");
+
+  let color = Color::parse("#0C2238").unwrap();
+  assert_eq!(color.rgb_value(), "12, 34, 56");
+}
+
+#[test]
+fn should_not_default_invalid_color_to_black() {
+  assert!(Color::from_rgb(String::from("12, 34")).is_err());
+  assert!(Color::parse("cmy(50%, 20%, 30%)").is_err());
+}
+
+#[test]
+fn should_keep_notation_errors_of_color_strings() {
+  assert_eq!(
+    Color::parse("rgb(300, 0, 0)").err(),
+    Some(ParseColorError::OutOfRange {
+      component: ColorComponent::Red,
+      value: 300.0
+    })
+  );
+  assert_eq!(
+    Color::parse("hsl(400, 10%, 10%)").err(),
+    Some(ParseColorError::OutOfRange {
+      component: ColorComponent::Hue,
+      value: 400.0
+    })
+  );
+  assert_eq!(
+    Color::parse("cmyk(120%, 0%, 0%, 0%)").err(),
+    Some(ParseColorError::OutOfRange {
+      component: ColorComponent::Cyan,
+      value: 120.0
+    })
+  );
+  assert_eq!(
+    Color::parse("zzz").err(),
+    Some(ParseColorError::UnknownFormat(String::from("zzz")))
+  );
+
+  assert_eq!(
+    Color::parse("rgb(100% 0% 0%)").unwrap().hex_value(),
+    "#ff0000"
+  );
+  assert_eq!(
+    Color::parse("hsl(120, 100%, 50%)").unwrap().hex_value(),
+    "#00ff00"
+  );
+}
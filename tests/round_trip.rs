@@ -0,0 +1,65 @@
+use std::thread;
+
+use color_picker::libs::color_transform::Color;
+
+// too slow without optimizations, run with `cargo test --release -- --ignored`
+#[test]
+#[ignore]
+fn should_keep_every_hex_color_through_rgb() {
+  // every thread checks the colors of 32 red values
+  let workers: Vec<_> = (0..8)
+    .map(|worker| {
+      thread::spawn(move || {
+        for value in worker << 21..(worker + 1) << 21 {
+          let hex = format!("#{:06x}", value);
+
+          let rgb = Color::from_hex(hex.clone()).unwrap().rgb_value();
+          let color = Color::from_rgb(rgb).unwrap();
+
+          assert_eq!(color.hex_value(), hex);
+        }
+      })
+    })
+    .collect();
+
+  for worker in workers {
+    worker.join().unwrap();
+  }
+}
+
+#[test]
+fn should_keep_hsl_values_without_drift() {
+  for hue in (0..360).step_by(5) {
+    for saturation in 0..=100 {
+      // white is the only lightness which loses its saturation
+      for lightness in 0..100 {
+        let hsl = format!("{}°, {}%, {}%", hue, saturation, lightness);
+        let color = Color::from_hsl(hsl.clone()).unwrap();
+
+        assert_eq!(color.hsl_value(), hsl);
+      }
+    }
+  }
+}
+
+#[test]
+fn should_keep_hsv_values_without_drift() {
+  for hue in (0..360).step_by(5) {
+    for saturation in 0..=100 {
+      for value in 0..=100 {
+        let hsv = format!("{}°, {}%, {}%", hue, saturation, value);
+        let color = Color::from_hsv(hsv.clone()).unwrap();
+
+        assert_eq!(color.hsv_value(), hsv);
+      }
+    }
+  }
+}
+
+#[test]
+fn should_keep_hue_of_black() {
+  let color = Color::from_hsv_values(200.0, 80.0, 0.0);
+  assert_eq!(color.hex_value(), "#000000");
+  assert_eq!(color.hsv_value(), "200°, 80%, 0%");
+  assert_eq!(color.cmyk_value(), "0%, 0%, 0%, 100%");
+}
@@ -15,6 +15,6 @@ fn should_determine_invalid_cmyk_string() {
 
 #[test]
 fn should_calculate_rgb_from_cmyk() {
-  let color = Color::from_cmyk(String::from("40%, 30%, 20%, 10%"));
+  let color = Color::from_cmyk(String::from("40%, 30%, 20%, 10%")).unwrap();
   assert_eq!(color.rgb_value(), "138, 161, 184");
 }
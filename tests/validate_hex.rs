@@ -15,6 +15,6 @@ fn should_determine_invalid_hex_string() {
 
 #[test]
 fn should_calculate_rgb_from_hex() {
-  let color = Color::from_hex(String::from("123456"));
+  let color = Color::from_hex(String::from("123456")).unwrap();
   assert_eq!(color.rgb_value(), "18, 52, 86");
 }
@@ -1,7 +1,26 @@
 use color_picker::libs::color_transform::Color;
+use color_picker::libs::color_validate;
+
+#[test]
+fn should_validate_hsl_string() {
+  let valid = color_validate::is_valid_hsl("120°,40%,  60%");
+  assert_eq!(valid, true);
+}
+
+#[test]
+fn should_determine_invalid_hsl_string() {
+  let valid = color_validate::is_valid_hsl("120°, 40%, 101%");
+  assert_eq!(valid, false);
+}
+
+#[test]
+fn should_reject_hsl_string_with_surrounding_text() {
+  let valid = color_validate::is_valid_hsl("hsl 120°, 40%, 60%;");
+  assert_eq!(valid, false);
+}
 
 #[test]
 fn should_calculate_rgb_from_hsl() {
-  let color = Color::from_hsl(String::from("300°, 50%, 20%"));
-  assert_eq!(color.rgb_value(), "77, 25, 77");
+  let color = Color::from_hsl(String::from("300°, 50%, 20%")).unwrap();
+  assert_eq!(color.rgb_value(), "77, 26, 77");
 }
@@ -15,7 +15,7 @@ fn should_determine_invalid_hsv_string() {
 
 #[test]
 fn should_calculate_rgb_from_hsl_string() {
-  let color = Color::from_hsv(String::from("250°, 100%, 50%"));
+  let color = Color::from_hsv(String::from("250°, 100%, 50%")).unwrap();
   assert_eq!(color.rgb_value(), "21, 0, 128");
 }
 
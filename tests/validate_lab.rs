@@ -0,0 +1,39 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::color_validate;
+
+#[test]
+fn should_validate_lab_string() {
+  let valid = color_validate::is_valid_lab("53.24, 80.09,  -67.2");
+  assert_eq!(valid, true);
+
+  let valid = color_validate::is_valid_lch("53.24, 104.55, 40°, 0.5");
+  assert_eq!(valid, true);
+}
+
+#[test]
+fn should_determine_invalid_lab_string() {
+  let valid = color_validate::is_valid_lab("101, 0, 0");
+  assert_eq!(valid, false);
+
+  let valid = color_validate::is_valid_lch("50, 20, 361°");
+  assert_eq!(valid, false);
+}
+
+#[test]
+fn should_calculate_lab_and_lch_from_rgb() {
+  let color = Color::from_rgb(String::from("255, 0, 0")).unwrap();
+  assert_eq!(color.lab_value(), "53.24, 80.09, 67.2");
+  assert_eq!(color.lch_value(), "53.24, 104.55, 40°");
+
+  let color = Color::from_hex(String::from("#ffffff")).unwrap();
+  assert_eq!(color.lab_value(), "100, 0, 0");
+}
+
+#[test]
+fn should_calculate_rgb_from_lab_and_lch_strings() {
+  let color = Color::from_lab(String::from("53.24, 80.09, 67.2")).unwrap();
+  assert_eq!(color.rgb_value(), "255, 0, 0");
+
+  let color = Color::from_lch(String::from("32.3, 133.81, 306.29°")).unwrap();
+  assert_eq!(color.hex_value(), "#0000ff");
+}
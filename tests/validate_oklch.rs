@@ -0,0 +1,51 @@
+use color_picker::libs::color_transform::Color;
+use color_picker::libs::color_validate;
+use color_picker::libs::css_color::{self, CssFormat};
+
+#[test]
+fn should_validate_oklch_string() {
+  let valid = color_validate::is_valid_oklch("62.8% 0.2577 29.23");
+  assert_eq!(valid, true);
+
+  let valid = color_validate::is_valid_oklab("45.2%  -0.0325 -0.3115 / 0.5");
+  assert_eq!(valid, true);
+}
+
+#[test]
+fn should_determine_invalid_oklch_string() {
+  let valid = color_validate::is_valid_oklch("62.8%, 0.2577, 29.23");
+  assert_eq!(valid, false);
+
+  let valid = color_validate::is_valid_oklch("62.8% 0.5 29.23");
+  assert_eq!(valid, false);
+}
+
+#[test]
+fn should_calculate_oklab_and_oklch_from_rgb() {
+  let color = Color::from_rgb(String::from("255, 0, 0")).unwrap();
+  assert_eq!(color.oklch_value(), "62.8% 0.2577 29.23");
+  assert_eq!(
+    color.to_css_string(CssFormat::Oklch),
+    "oklch(62.8% 0.2577 29.23)"
+  );
+
+  let color = Color::from_hex(String::from("#0000ff80")).unwrap();
+  assert_eq!(color.oklab_value(), "45.2% -0.0325 -0.3115 / 0.5");
+}
+
+#[test]
+fn should_calculate_rgb_from_oklch_string() {
+  let color = Color::from_oklch(String::from("62.8% 0.2577 29.23")).unwrap();
+  assert_eq!(color.hex_value(), "#ff0000");
+
+  let color = Color::from_oklab(String::from("45.2% -0.0325 -0.3115")).unwrap();
+  assert_eq!(color.hex_value(), "#0000ff");
+}
+
+#[test]
+fn should_read_its_own_css_string() {
+  let color = Color::from_oklch(String::from("70% 0.1 200")).unwrap();
+  let css = css_color::parse(&color.to_css_string(CssFormat::Oklch)).unwrap();
+
+  assert_eq!(css.hex_value(), color.hex_value());
+}
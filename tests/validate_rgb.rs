@@ -15,6 +15,6 @@ fn should_determine_invalid_rgb_string() {
 
 #[test]
 fn should_calculate_hex_from_rgb() {
-  let color = Color::from_rgb(String::from("12, 34, 56"));
+  let color = Color::from_rgb(String::from("12, 34, 56")).unwrap();
   assert_eq!(color.hex_value(), "#0c2238");
 }
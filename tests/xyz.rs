@@ -0,0 +1,77 @@
+use color_picker::libs::color_transform::oklab_color::Oklab;
+use color_picker::libs::color_transform::xyz_color::{Adaptation, WhitePoint, Xyz};
+use color_picker::libs::color_transform::Color;
+
+fn assert_close(actual: [f64; 3], expected: [f64; 3]) {
+  for (actual, expected) in actual.iter().zip(expected.iter()) {
+    assert!(
+      (actual - expected).abs() < 1e-4,
+      "{:?} != {:?}",
+      actual,
+      expected
+    );
+  }
+}
+
+#[test]
+fn should_calculate_xyz_from_rgb() {
+  let xyz = Color::from_hex(String::from("#ffffff")).unwrap().to_xyz();
+  assert_eq!(xyz.get_white(), WhitePoint::D65);
+  assert_eq!(xyz.to_string(), "95.05, 100, 108.91");
+}
+
+#[test]
+fn should_map_white_to_white() {
+  let d65 = WhitePoint::D65.xyz();
+  let white = Xyz::from_values(d65[0], d65[1], d65[2], WhitePoint::D65, 1.0);
+
+  for method in [
+    Adaptation::Bradford,
+    Adaptation::VonKries,
+    Adaptation::XyzScaling,
+  ]
+  .iter()
+  {
+    let adapted = white.adapt(WhitePoint::A, *method);
+    assert_close(adapted.get_values(), WhitePoint::A.xyz());
+  }
+}
+
+#[test]
+fn should_adapt_srgb_red_to_d50_with_bradford() {
+  let red = Color::from_rgb(String::from("255, 0, 0")).unwrap().to_xyz();
+  let adapted = red.adapt(WhitePoint::D50, Adaptation::Bradford);
+
+  assert_close(adapted.get_values(), [0.43607, 0.22249, 0.01392]);
+}
+
+#[test]
+fn should_convert_back_through_other_white_points() {
+  let color = Color::from_hex(String::from("#3a7bd5")).unwrap();
+  let xyz = color
+    .to_xyz()
+    .adapt(WhitePoint::F2, Adaptation::Bradford)
+    .adapt(WhitePoint::D50, Adaptation::Bradford);
+
+  assert_eq!(Color::from_xyz(&xyz).hex_value(), "#3a7bd5");
+}
+
+#[test]
+fn should_convert_oklab_through_xyz() {
+  let white = Oklab::from_xyz(&Color::from_hex(String::from("#ffffff")).unwrap().to_xyz());
+  assert_close(
+    [white.get_lightness(), white.get_a(), white.get_b()],
+    [1.0, 0.0, 0.0],
+  );
+
+  // the same color seen under D50 lands on the same OKLab value
+  let xyz = Color::from_hex(String::from("#6495ed")).unwrap().to_xyz();
+  let from_d65 = Oklab::from_xyz(&xyz);
+  let from_d50 = Oklab::from_xyz(&xyz.adapt(WhitePoint::D50, Adaptation::Bradford));
+  assert_close(
+    [from_d50.get_lightness(), from_d50.get_a(), from_d50.get_b()],
+    [from_d65.get_lightness(), from_d65.get_a(), from_d65.get_b()],
+  );
+
+  assert_close(Xyz::from_oklab(&from_d65).get_values(), xyz.get_values());
+}